@@ -11,7 +11,7 @@ pub fn main() {
         };
 
         let player = player_generator(None, None, None).generate();
-        let state = game_generator().generate();
+        let state = game_generator(None, None, None).generate();
         let mut game = Game { state, player };
 
         let first_exit_id = match game.state.current_room_exits().get(0) {
@@ -33,6 +33,7 @@ pub fn main() {
             npc_id: npc_id.to_string(),
             discover_health: true,
             discover_packed_items: true,
+            discover_attributes: true,
         };
         game.handle_action(&Action::InspectNpc(inspect)).unwrap();
         let attack = AttackNpc {
@@ -14,8 +14,10 @@ pub enum Action {
     AttackNpc(super::AttackNpc),
     CastSpellOnNpc(super::CastSpellOnNpc),
     CastSpellOnPlayer(super::CastSpellOnPlayer),
+    DisarmTrap(super::DisarmTrap),
     ExitRoom(super::ExitRoom),
     InspectFixture(super::InspectFixture),
+    InspectItem(super::InspectItem),
     InspectNpc(super::InspectNpc),
     LookAtFixture(super::LookAtFixture),
     LookAtNpc(super::LookAtNpc),
@@ -25,7 +27,12 @@ pub enum Action {
     OpenFixture(super::OpenFixture),
     OpenFixtureHiddenCompartment(super::OpenFixtureHiddenCompartment),
     PickUpItem(super::PickUpItem),
+    RepairItem(super::RepairItem),
+    SearchRoom(super::SearchRoom),
     SellPlayerItem(super::SellPlayerItem),
     UseItemOnPlayer(super::UseItemOnPlayer),
     ThrowItemAtNpc(super::ThrowItemAtNpc),
+    TravelToRoom(super::TravelToRoom),
+    UnlockExit(super::UnlockExit),
+    UnlockFixture(super::UnlockFixture),
 }
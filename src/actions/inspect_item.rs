@@ -0,0 +1,29 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+/// Inspect an item the player can see, with a chance to identify
+/// what it does.
+pub struct InspectItem {
+    pub item_id: String,
+    /// Attempt to discover the item's attack.
+    pub discover_attack: bool,
+    /// Attempt to discover the item's defense.
+    pub discover_defense: bool,
+    /// Attempt to identify what a consumable does when used.
+    pub discover_consumable: bool,
+    /// Attempt to discover any enchantments or curses on the item.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub discover_affixes: bool,
+}
@@ -21,4 +21,7 @@ pub struct InspectNpc {
     pub discover_health: bool,
     /// Attempt to discover the items the NPC has packed away.
     pub discover_packed_items: bool,
+    /// Attempt to discover the NPC's attributes.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub discover_attributes: bool,
 }
@@ -2,8 +2,10 @@ pub mod action;
 pub mod attack_npc;
 pub mod cast_spell_on_npc;
 pub mod cast_spell_on_player;
+pub mod disarm_trap;
 pub mod exit_room;
 pub mod inspect_fixture;
+pub mod inspect_item;
 pub mod inspect_npc;
 pub mod look_at_fixture;
 pub mod look_at_npc;
@@ -13,16 +15,23 @@ pub mod move_player_item;
 pub mod open_fixture;
 pub mod open_fixture_hidden_compartment;
 mod pick_up_item;
+pub mod repair_item;
+pub mod search_room;
 pub mod sell_player_item;
 mod throw_item_at_npc;
+pub mod travel_to_room;
+pub mod unlock_exit;
+pub mod unlock_fixture;
 pub mod use_item_on_player;
 
 pub use {
     action::Action, attack_npc::AttackNpc, cast_spell_on_npc::CastSpellOnNpc,
-    cast_spell_on_player::CastSpellOnPlayer, exit_room::ExitRoom, inspect_fixture::InspectFixture,
-    inspect_npc::InspectNpc, look_at_fixture::LookAtFixture, look_at_npc::LookAtNpc,
-    loot_fixture::LootFixture, loot_npc::LootNpc, move_player_item::MovePlayerItem,
-    open_fixture::OpenFixture, open_fixture_hidden_compartment::OpenFixtureHiddenCompartment,
-    pick_up_item::PickUpItem, sell_player_item::SellPlayerItem, throw_item_at_npc::ThrowItemAtNpc,
-    use_item_on_player::UseItemOnPlayer,
+    cast_spell_on_player::CastSpellOnPlayer, disarm_trap::DisarmTrap, exit_room::ExitRoom,
+    inspect_fixture::InspectFixture, inspect_item::InspectItem, inspect_npc::InspectNpc,
+    look_at_fixture::LookAtFixture, look_at_npc::LookAtNpc, loot_fixture::LootFixture,
+    loot_npc::LootNpc, move_player_item::MovePlayerItem, open_fixture::OpenFixture,
+    open_fixture_hidden_compartment::OpenFixtureHiddenCompartment, pick_up_item::PickUpItem,
+    repair_item::RepairItem, search_room::SearchRoom, sell_player_item::SellPlayerItem,
+    throw_item_at_npc::ThrowItemAtNpc, travel_to_room::TravelToRoom, unlock_exit::UnlockExit,
+    unlock_fixture::UnlockFixture, use_item_on_player::UseItemOnPlayer,
 };
@@ -0,0 +1,19 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use super::AchievementRule;
+
+/// Something a player can unlock once across all of their runs. Achievements
+/// are plain data, so new ones can be loaded without changing the crate.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct Achievement {
+    /// Stays the same for as long as the achievement exists, since profiles refer to it.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub rule: AchievementRule,
+}
@@ -0,0 +1,37 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use crate::components::{
+    games::{Statistic, VictoryCondition},
+    items::ItemType,
+    spells::SpellName,
+    Species,
+};
+
+/// What has to happen in a turn for an achievement to be unlocked.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case", tag = "rule_type")
+)]
+pub enum AchievementRule {
+    /// Kill an NPC. Every condition that is set has to hold for the NPC killed
+    /// and the action the player took that turn.
+    KillNpc {
+        species: Option<Species>,
+        #[cfg_attr(feature = "serialization", serde(default))]
+        boss: bool,
+        thrown_item_type: Option<ItemType>,
+        spell_name: Option<SpellName>,
+    },
+    /// Kill the last NPC standing in a room without taking any damage in it.
+    ClearRoomUnharmed,
+    /// Get one of the run statistics up to a value.
+    ReachStatistic { statistic: Statistic, value: i64 },
+    /// Win a game, either in a particular way or any way at all.
+    WinGame { victory: Option<VictoryCondition> },
+}
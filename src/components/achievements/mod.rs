@@ -0,0 +1,7 @@
+mod achievement;
+mod achievement_rule;
+mod player_profile;
+
+pub use achievement::Achievement;
+pub use achievement_rule::AchievementRule;
+pub use player_profile::{PlayerProfile, UnlockedAchievement};
@@ -0,0 +1,33 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What a player keeps from one run to the next.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct PlayerProfile {
+    pub id: Uuid,
+    pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub unlocked_achievements: Vec<UnlockedAchievement>,
+}
+
+impl PlayerProfile {
+    pub fn has_unlocked(&self, achievement_id: &str) -> bool {
+        self.unlocked_achievements
+            .iter()
+            .any(|unlocked| unlocked.achievement_id == achievement_id)
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct UnlockedAchievement {
+    pub achievement_id: String,
+    pub unlocked_at: DateTime<Utc>,
+}
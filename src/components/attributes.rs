@@ -0,0 +1,67 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+pub const AVERAGE_ATTRIBUTE: i32 = 5;
+pub const MIN_ATTRIBUTE: i32 = 1;
+pub const MAX_ATTRIBUTE: i32 = 10;
+
+/// Core attributes of a character. Each one sits between 1 and 10, with 5 being
+/// average. Anything above or below average gives a modifier that feeds into combat
+/// and discovery rolls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct Attributes {
+    /// Raw strength, added to attack rolls.
+    pub might: i32,
+    /// Quickness, used when trying to dodge an attack.
+    pub agility: i32,
+    /// Perception, used when inspecting NPCs and fixtures.
+    pub wits: i32,
+    /// Toughness of body and mind, used to resist poison.
+    pub resolve: i32,
+}
+
+impl Default for Attributes {
+    fn default() -> Self {
+        Self {
+            might: AVERAGE_ATTRIBUTE,
+            agility: AVERAGE_ATTRIBUTE,
+            wits: AVERAGE_ATTRIBUTE,
+            resolve: AVERAGE_ATTRIBUTE,
+        }
+    }
+}
+
+impl Attributes {
+    pub fn might_modifier(&self) -> i32 {
+        modifier(self.might)
+    }
+
+    pub fn agility_modifier(&self) -> i32 {
+        modifier(self.agility)
+    }
+
+    pub fn wits_modifier(&self) -> i32 {
+        modifier(self.wits)
+    }
+
+    pub fn resolve_modifier(&self) -> i32 {
+        modifier(self.resolve)
+    }
+
+    /// How much poison damage is shrugged off each turn. Below average resolve
+    /// never makes poison worse.
+    pub fn poison_resistance(&self) -> i32 {
+        self.resolve_modifier().max(0)
+    }
+}
+
+fn modifier(value: i32) -> i32 {
+    (value.clamp(MIN_ATTRIBUTE, MAX_ATTRIBUTE) - AVERAGE_ATTRIBUTE) / 2
+}
@@ -8,7 +8,7 @@ use uuid::Uuid;
 
 use super::{
     damage::AttackEffect,
-    items::CharacterItem,
+    items::{Affix, CharacterItem},
     spells::{
         LearnedSpell, {SpellMemory, SpellMemoryView},
     },
@@ -16,6 +16,9 @@ use super::{
     {Stats, StatsView},
 };
 
+const CARRY_CAPACITY_PER_MIGHT: i32 = 5;
+const ENCUMBERED_PERCENT: i32 = 75;
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
@@ -41,6 +44,10 @@ impl Character {
         self.inventory.find_item(item_id)
     }
 
+    pub fn find_item_mut(&mut self, item_id: &Uuid) -> Option<&mut CharacterItem> {
+        self.inventory.find_item_mut(item_id)
+    }
+
     pub fn find_spell(&self, spell_id: &Uuid) -> Option<&LearnedSpell> {
         self.spell_memory.find_spell(spell_id)
     }
@@ -119,6 +126,38 @@ impl Character {
         self.inventory.strongest_non_readied_weapon()
     }
 
+    pub fn magic_resistance(&self) -> i32 {
+        self.inventory.magic_resistance()
+    }
+
+    pub fn has_readied_affix(&self, affix: &Affix) -> bool {
+        self.inventory.has_readied_affix(affix)
+    }
+
+    pub fn has_warm_clothing(&self) -> bool {
+        self.inventory.has_warm_clothing()
+    }
+
+    pub fn carried_weight(&self) -> i32 {
+        self.inventory.total_weight()
+    }
+
+    /// How much weight the character can carry before they can't pick up anything else.
+    pub fn carry_capacity(&self) -> i32 {
+        self.species.base_carry_capacity()
+            + self.stats.height.carry_capacity_modifier()
+            + self.stats.attributes.might_modifier() * CARRY_CAPACITY_PER_MIGHT
+    }
+
+    pub fn can_carry(&self, additional_weight: i32) -> bool {
+        self.carried_weight() + additional_weight <= self.carry_capacity()
+    }
+
+    /// Carrying more than three quarters of the capacity makes it harder to dodge.
+    pub fn is_encumbered(&self) -> bool {
+        self.carried_weight() * 100 > self.carry_capacity() * ENCUMBERED_PERCENT
+    }
+
     pub fn full_attack(&self) -> Attack {
         let base_attack = self.stats.base_attack.clone().unwrap_or_default();
         let inventory_full_attack = self.inventory.full_attack().unwrap_or_default();
@@ -134,7 +173,9 @@ impl Character {
 
         Attack {
             num_rolls: inventory_full_attack.num_rolls + base_attack.num_rolls,
-            modifier: inventory_full_attack.modifier + base_attack.modifier,
+            modifier: inventory_full_attack.modifier
+                + base_attack.modifier
+                + self.stats.attributes.might_modifier(),
             effects,
         }
     }
@@ -182,6 +223,10 @@ pub struct CharacterViewArgs {
     pub knows_health: bool,
     pub knows_inventory: bool,
     pub knows_packed_in_inventory: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_attributes: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_spells: bool,
 }
 
 impl CharacterViewArgs {
@@ -190,6 +235,8 @@ impl CharacterViewArgs {
             knows_health: true,
             knows_inventory: true,
             knows_packed_in_inventory: true,
+            knows_attributes: true,
+            knows_spells: true,
         }
     }
 }
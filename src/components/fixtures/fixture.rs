@@ -10,7 +10,7 @@ use crate::components::{
     items::{
         Descriptor, {FixtureItem, FixtureItemView},
     },
-    Material, Size,
+    Lock, Material, Size, Trap, TrapView,
 };
 
 use super::FixtureType;
@@ -37,9 +37,17 @@ pub struct Fixture {
     pub open: bool,
     pub has_hidden_compartment: bool,
     pub hidden_compartment_open: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub lock: Option<Lock>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub trap: Option<Trap>,
 }
 
 impl Fixture {
+    pub fn is_locked(&self) -> bool {
+        self.lock.as_ref().is_some_and(|lock| lock.locked)
+    }
+
     pub fn remove_item(&mut self, item_id: &Uuid) -> Option<FixtureItem> {
         let index = self
             .items
@@ -77,17 +85,23 @@ pub struct FixtureView {
     pub open: bool,
     pub can_be_opened: bool,
     pub hidden_compartment_open: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_locked: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub trap: Option<TrapView>,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct FixtureViewArgs {
     pub knows_has_hidden_compartment: bool,
+    pub knows_has_trap: bool,
 }
 
 impl FixtureViewArgs {
     pub fn knows_all_args() -> FixtureViewArgs {
         FixtureViewArgs {
             knows_has_hidden_compartment: true,
+            knows_has_trap: true,
         }
     }
 }
@@ -35,6 +35,13 @@ pub enum FixtureType {
     WeaponRack,
 }
 
+impl FixtureType {
+    /// Fixtures sturdy enough to work on gear at.
+    pub fn can_repair_items(&self) -> bool {
+        matches!(*self, FixtureType::Table | FixtureType::WeaponRack)
+    }
+}
+
 impl Tagged for FixtureType {
     fn tags(&self) -> Vec<Tag> {
         match *self {
@@ -15,6 +15,8 @@ pub struct CharacterKnowledge {
     pub knows_life_modifier: bool,
     pub knows_inventory: bool,
     pub knows_packed_in_inventory: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_attributes: bool,
 }
 
 impl Default for CharacterKnowledge {
@@ -25,6 +27,7 @@ impl Default for CharacterKnowledge {
             knows_life_modifier: true,
             knows_inventory: true,
             knows_packed_in_inventory: false,
+            knows_attributes: false,
         }
     }
 }
@@ -11,4 +11,6 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "openapi", derive(Object))]
 pub struct FixtureKnowledge {
     pub knows_has_hidden_compartment: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_has_trap: bool,
 }
@@ -0,0 +1,52 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Statistics, VictoryCondition};
+
+/// How a game ended, with a summary of the run.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct GameOutcome {
+    /// The condition the player met, or None if they died.
+    pub victory: Option<VictoryCondition>,
+    pub statistics: Statistics,
+    pub rooms_seen: usize,
+    pub gold: u32,
+    pub danger_level: u32,
+    pub deepest_depth: u32,
+    pub killer_id: Option<Uuid>,
+    pub killer_name: Option<String>,
+}
+
+impl GameOutcome {
+    pub fn is_victory(&self) -> bool {
+        self.victory.is_some()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "GameOutcome"))]
+pub struct GameOutcomeView {
+    pub victory: Option<VictoryCondition>,
+    pub statistics: Statistics,
+    /// The score of the run, from its statistics.
+    pub score: i64,
+    pub rooms_seen: usize,
+    pub gold: u32,
+    pub danger_level: u32,
+    pub deepest_depth: u32,
+    pub killer_id: Option<String>,
+    pub killer_name: Option<String>,
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 #[cfg(feature = "bevy_components")]
 use bevy_ecs::prelude::Component;
@@ -11,14 +11,20 @@ use uuid::Uuid;
 use crate::{
     components::{
         fixtures::FixtureViewArgs,
-        rooms::{Room, RoomView},
+        items::ItemViewArgs,
+        quests::{Quest, QuestView},
+        rooms::{Descriptor, Room, RoomView},
         worlds::{World, WorldView},
-        CharacterViewArgs, Ghost, NonPlayerViewArgs,
+        CharacterViewArgs, Ghost, NonPlayer, NonPlayerViewArgs,
     },
+    generators::ContentPack,
     systems::view::room::view,
 };
 
-use super::{CharacterKnowledge, FixtureKnowledge, Statistics};
+use super::{
+    CharacterKnowledge, FixtureKnowledge, GameOutcome, GameOutcomeView, ItemKnowledge, Ruleset,
+    Statistics, VictoryConditions,
+};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -37,11 +43,32 @@ pub struct GameState {
     #[cfg_attr(feature = "serialization", serde(default))]
     pub player_fixture_knowledge: HashMap<Uuid, FixtureKnowledge>,
     #[cfg_attr(feature = "serialization", serde(default))]
+    pub player_item_knowledge: HashMap<Uuid, ItemKnowledge>,
+    #[cfg_attr(feature = "serialization", serde(default))]
     pub player_statistics: HashMap<Uuid, Statistics>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub danger_level: u32,
+    /// Traps in rooms and on exits the player has found. Traps on fixtures are
+    /// part of the fixture knowledge instead.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub known_trap_ids: Vec<Uuid>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub quests: Vec<Quest>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub ruleset: Ruleset,
+    /// Damage the player has taken since they last entered a room.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub damage_taken_in_room: i32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub victory_conditions: VictoryConditions,
+    /// Set once the game has been won or lost. No more actions can be taken after that.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub outcome: Option<GameOutcome>,
     #[cfg_attr(feature = "serialization", serde(default, skip))]
     pub ghosts: Vec<Ghost>,
+    /// Content the rooms, NPCs and items of this game are generated from.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub content_pack: Arc<ContentPack>,
 }
 
 impl GameState {
@@ -63,10 +90,43 @@ impl GameState {
             .unwrap_or_default()
     }
 
+    pub fn item_knowledge(&self, item_id: &Uuid) -> ItemKnowledge {
+        self.player_item_knowledge
+            .get(item_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn set_item_knowledge(&mut self, item_id: Uuid, knowledge: ItemKnowledge) {
+        self.player_item_knowledge.insert(item_id, knowledge);
+    }
+
+    pub fn item_view_args(&self) -> HashMap<Uuid, ItemViewArgs> {
+        self.player_item_knowledge
+            .iter()
+            .map(|(item_id, knowledge)| {
+                (
+                    *item_id,
+                    ItemViewArgs {
+                        knows_attack: knowledge.knows_attack,
+                        knows_defense: knowledge.knows_defense,
+                        knows_consumable: knowledge.knows_consumable,
+                        knows_affixes: knowledge.knows_affixes,
+                        carried_by_player: false,
+                    },
+                )
+            })
+            .collect()
+    }
+
     pub fn set_npc_knowledge(&mut self, npc_id: Uuid, knowledge: CharacterKnowledge) {
         self.player_npc_knowledge.insert(npc_id, knowledge);
     }
 
+    pub fn player_stats_mut(&mut self, pc_id: &Uuid) -> &mut Statistics {
+        self.player_statistics.entry(*pc_id).or_default()
+    }
+
     pub fn add_player_kill_to_stats(&mut self, pc_id: &Uuid) {
         let statistics = self.player_statistics.entry(*pc_id).or_default();
         statistics.num_killed += 1;
@@ -75,6 +135,7 @@ impl GameState {
     pub fn add_player_damage_taken_to_stats(&mut self, pc_id: &Uuid, damage: i32) {
         let statistics = self.player_statistics.entry(*pc_id).or_default();
         statistics.total_damage_taken += damage;
+        self.damage_taken_in_room += damage;
     }
 
     pub fn add_player_damage_done_to_stats(&mut self, pc_id: &Uuid, damage: i32) {
@@ -93,6 +154,42 @@ impl GameState {
         self.player_fixture_knowledge.insert(fixture_id, knowledge);
     }
 
+    /// Whether the player has found a trap in the current room, either by searching
+    /// the room or by inspecting the fixture it is set on.
+    pub fn knows_trap(&self, trap_id: &Uuid) -> bool {
+        if self.all_knowledge_unlocked || self.known_trap_ids.contains(trap_id) {
+            return true;
+        }
+
+        self.current_room()
+            .find_fixture_with_trap(trap_id)
+            .is_some_and(|position| self.fixture_knowledge(&position.fixture.id).knows_has_trap)
+    }
+
+    /// Looks for an NPC in every room of the world, not only the current one.
+    pub fn find_npc(&self, npc_id: &Uuid) -> Option<&NonPlayer> {
+        self.world
+            .rooms
+            .iter()
+            .flat_map(|room| room.npc_positions.iter())
+            .map(|position| &position.npc)
+            .find(|npc| npc.id.eq(npc_id))
+    }
+
+    pub fn find_quest_mut(&mut self, quest_id: &Uuid) -> Option<&mut Quest> {
+        self.quests.iter_mut().find(|quest| quest.id.eq(quest_id))
+    }
+
+    pub fn active_quests(&self) -> impl Iterator<Item = &Quest> {
+        self.quests
+            .iter()
+            .filter(|quest| !quest.completed && !quest.failed)
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some()
+    }
+
     pub fn current_room_exits(&self) -> Vec<Uuid> {
         self.current_room()
             .exits
@@ -131,10 +228,13 @@ impl GameState {
                 fixture_id,
                 FixtureViewArgs {
                     knows_has_hidden_compartment: knowledge.knows_has_hidden_compartment,
+                    knows_has_trap: knowledge.knows_has_trap,
                 },
             );
         }
 
+        // Nothing can be made out about the NPCs in a dark room, whatever the player knows.
+        let is_dark = room.has_descriptor(&Descriptor::Dark);
         let mut npc_args: HashMap<Uuid, NonPlayerViewArgs> = HashMap::new();
 
         for npc_id in room
@@ -143,7 +243,11 @@ impl GameState {
             .map(|npc_position| &npc_position.npc)
             .map(|npc| npc.id)
         {
-            let knowledge = self.npc_knowledge(&npc_id);
+            let knowledge = if is_dark {
+                CharacterKnowledge::default()
+            } else {
+                self.npc_knowledge(&npc_id)
+            };
             npc_args.insert(
                 npc_id,
                 NonPlayerViewArgs {
@@ -151,6 +255,8 @@ impl GameState {
                         knows_health: knowledge.knows_health,
                         knows_inventory: knowledge.knows_inventory,
                         knows_packed_in_inventory: knowledge.knows_packed_in_inventory,
+                        knows_attributes: knowledge.knows_attributes,
+                        knows_spells: false,
                     },
                 },
             );
@@ -178,7 +284,9 @@ impl GameState {
             room,
             npc_args,
             fixture_args,
+            self.item_view_args(),
             exit_visitations,
+            &self.known_trap_ids,
             self.all_knowledge_unlocked,
         )
     }
@@ -205,8 +313,12 @@ pub struct GameStateView {
     pub all_knowledge_unlocked: bool,
     pub player_npc_knowledge: HashMap<String, CharacterKnowledge>,
     pub player_fixture_knowledge: HashMap<String, FixtureKnowledge>,
+    pub player_item_knowledge: HashMap<String, ItemKnowledge>,
     pub player_statistics: HashMap<String, Statistics>,
     pub danger_level: u32,
+    pub quests: Vec<QuestView>,
+    pub victory_conditions: VictoryConditions,
+    pub outcome: Option<GameOutcomeView>,
 }
 
 #[cfg(test)]
@@ -217,7 +329,7 @@ mod tests {
 
     #[test]
     fn stats_can_be_incremented() {
-        let mut state = game_generator().generate();
+        let mut state = game_generator(None, None, None).generate();
         let pc_id = Uuid::new_v4();
         state.add_player_kill_to_stats(&pc_id);
         let stats = state.player_stats(&pc_id);
@@ -245,7 +357,7 @@ mod tests {
 
         use super::GameState;
         use crate::components::{
-            spells::SpellMemory, Character, Effects, Ghost, Health, Inventory, Stats,
+            spells::SpellMemory, Attributes, Character, Effects, Ghost, Health, Inventory, Stats,
         };
 
         let text = fs::read_to_string("./fixtures/game.json").unwrap();
@@ -258,6 +370,7 @@ mod tests {
                     height: crate::components::Size::Average,
                     base_attack: None,
                     base_damage_resistance: None,
+                    attributes: Attributes::default(),
                 },
                 species: crate::components::Species::Bugbear,
                 life_modifier: None,
@@ -266,6 +379,7 @@ mod tests {
                 spell_memory: SpellMemory::default(),
             },
             name: None,
+            killer_name: None,
         });
 
         let serialized = serde_json::to_string(&game_state).unwrap();
@@ -0,0 +1,18 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct ItemKnowledge {
+    pub knows_attack: bool,
+    pub knows_defense: bool,
+    pub knows_consumable: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_affixes: bool,
+}
@@ -1,9 +1,17 @@
 pub mod character_knowledge;
 pub mod fixture_knowledge;
+pub mod game_outcome;
 pub mod game_state;
+pub mod item_knowledge;
+pub mod ruleset;
 pub mod statistics;
+pub mod victory_condition;
 
 pub use character_knowledge::CharacterKnowledge;
 pub use fixture_knowledge::FixtureKnowledge;
+pub use game_outcome::{GameOutcome, GameOutcomeView};
 pub use game_state::GameState;
-pub use statistics::Statistics;
+pub use item_knowledge::ItemKnowledge;
+pub use ruleset::{Ruleset, RulesetPreset, RulesetPresetIter};
+pub use statistics::{Statistic, StatisticIter, Statistics};
+pub use victory_condition::{VictoryCondition, VictoryConditionIter, VictoryConditions};
@@ -0,0 +1,164 @@
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum RulesetPreset {
+    Easy,
+    Normal,
+    Brutal,
+}
+
+impl RulesetPreset {
+    pub fn ruleset(&self) -> Ruleset {
+        match *self {
+            RulesetPreset::Easy => Ruleset::easy(),
+            RulesetPreset::Normal => Ruleset::normal(),
+            RulesetPreset::Brutal => Ruleset::brutal(),
+        }
+    }
+}
+
+/// The balance of a game. Anything left out when deserializing takes its
+/// value from the normal preset.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(default)
+)]
+pub struct Ruleset {
+    /// Added to the player's agility modifier. The player dodges an NPC's
+    /// attack when a d6 rolls that or under.
+    pub player_dodge_chance: i32,
+    /// Percent chance an acidic NPC weapon eats away one of the player's weapons.
+    pub npc_acid_destroys_item_chance: i32,
+    /// Percent chance an acidic player weapon eats away one of the NPC's weapons.
+    pub player_acid_destroys_item_chance: i32,
+    /// Percent chance an acid splash eats away one of its target's weapons.
+    pub acid_splash_destroys_item_chance: i32,
+    /// Poison from toxic weapons, whoever is holding them.
+    pub toxic_damage: RangeInclusive<i32>,
+    pub toxic_duration: RangeInclusive<i32>,
+    pub poison_dart_damage: RangeInclusive<i32>,
+    pub poison_dart_duration: RangeInclusive<i32>,
+    pub poison_cloud_damage: RangeInclusive<i32>,
+    pub poison_cloud_duration: RangeInclusive<i32>,
+    /// What a d6 plus the player's wits modifier has to reach for an inspection
+    /// to turn up an NPC's health.
+    pub discover_health_chance: i32,
+    pub discover_packed_chance: i32,
+    pub discover_attributes_chance: i32,
+    /// Percent chance for an NPC to be the ghost of an earlier player instead,
+    /// at most once per room.
+    pub spawn_from_ghost_chance: i32,
+    /// Percent chance for each member of a group to be the same species as the one before.
+    pub keep_species_chance: i32,
+}
+
+impl Ruleset {
+    pub fn easy() -> Self {
+        Self {
+            player_dodge_chance: 2,
+            npc_acid_destroys_item_chance: 10,
+            player_acid_destroys_item_chance: 35,
+            acid_splash_destroys_item_chance: 90,
+            poison_dart_damage: 3..=7,
+            poison_cloud_damage: 2..=9,
+            discover_health_chance: 4,
+            discover_packed_chance: 3,
+            discover_attributes_chance: 3,
+            spawn_from_ghost_chance: 5,
+            ..Self::normal()
+        }
+    }
+
+    pub fn normal() -> Self {
+        Self {
+            player_dodge_chance: 1,
+            npc_acid_destroys_item_chance: 20,
+            player_acid_destroys_item_chance: 25,
+            acid_splash_destroys_item_chance: 75,
+            toxic_damage: 3..=6,
+            toxic_duration: 2..=4,
+            poison_dart_damage: 2..=6,
+            poison_dart_duration: 1..=4,
+            poison_cloud_damage: 1..=8,
+            poison_cloud_duration: 2..=5,
+            discover_health_chance: 5,
+            discover_packed_chance: 4,
+            discover_attributes_chance: 4,
+            spawn_from_ghost_chance: 10,
+            keep_species_chance: 90,
+        }
+    }
+
+    pub fn brutal() -> Self {
+        Self {
+            player_dodge_chance: 0,
+            npc_acid_destroys_item_chance: 40,
+            player_acid_destroys_item_chance: 15,
+            acid_splash_destroys_item_chance: 60,
+            toxic_damage: 4..=8,
+            toxic_duration: 3..=5,
+            poison_dart_damage: 1..=5,
+            poison_cloud_damage: 1..=6,
+            discover_health_chance: 6,
+            discover_packed_chance: 5,
+            discover_attributes_chance: 5,
+            spawn_from_ghost_chance: 25,
+            ..Self::normal()
+        }
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Self::normal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "json")]
+    fn missing_rules_come_from_the_normal_preset() {
+        use super::Ruleset;
+
+        let ruleset: Ruleset = serde_json::from_str(r#"{ "player_dodge_chance": 3 }"#).unwrap();
+
+        assert_eq!(3, ruleset.player_dodge_chance);
+        assert_eq!(
+            Ruleset::normal().spawn_from_ghost_chance,
+            ruleset.spawn_from_ghost_chance
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn backwards_ranges_still_roll() {
+        use crate::utils::rolls::roll_range;
+
+        use super::Ruleset;
+
+        let ruleset: Ruleset =
+            serde_json::from_str(r#"{ "toxic_damage": { "start": 5, "end": 3 } }"#).unwrap();
+
+        let damage = roll_range(&mut rand::thread_rng(), &ruleset.toxic_damage);
+        assert!((3..=5).contains(&damage));
+    }
+}
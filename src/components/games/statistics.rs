@@ -1,9 +1,20 @@
+use std::collections::HashMap;
+
 #[cfg(feature = "bevy_components")]
 use bevy_ecs::prelude::Component;
 #[cfg(feature = "openapi")]
-use poem_openapi::Object;
+use poem_openapi::{Enum, Object};
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::components::{spells::SpellName, Species};
+
+const POINTS_PER_ROOM: i64 = 10;
+const POINTS_PER_KILL: i64 = 25;
+const POINTS_PER_ITEM_LOOTED: i64 = 5;
+const POINTS_PER_HIDDEN_COMPARTMENT: i64 = 20;
+const POINTS_PER_SPELL_CAST: i64 = 2;
 
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -13,4 +24,107 @@ pub struct Statistics {
     pub num_killed: i64,
     pub total_damage_taken: i32,
     pub total_damage_done: i32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub rooms_explored: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub kills_by_species: HashMap<Species, u32>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub spells_cast: HashMap<SpellName, u32>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub items_looted: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub gold_earned: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub hidden_compartments_found: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub poison_damage_done: i32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub turns_taken: u32,
+}
+
+impl Statistics {
+    pub fn get(&self, statistic: &Statistic) -> i64 {
+        match *statistic {
+            Statistic::GoldEarned => self.gold_earned as i64,
+            Statistic::HiddenCompartmentsFound => self.hidden_compartments_found as i64,
+            Statistic::ItemsLooted => self.items_looted as i64,
+            Statistic::NumKilled => self.num_killed,
+            Statistic::PoisonDamageDone => self.poison_damage_done as i64,
+            Statistic::RoomsExplored => self.rooms_explored as i64,
+            Statistic::Score => self.score(),
+            Statistic::SpellsCast => self.spells_cast.values().sum::<u32>() as i64,
+            Statistic::TotalDamageDone => self.total_damage_done as i64,
+            Statistic::TotalDamageTaken => self.total_damage_taken as i64,
+            Statistic::TurnsTaken => self.turns_taken as i64,
+        }
+    }
+
+    /// A single number to compare runs by. Every room explored is worth 10
+    /// points, every kill 25, every item looted 5, every hidden compartment
+    /// found 20 and every spell cast 2. Each point of damage done, directly or
+    /// by poison, and each piece of gold earned adds one more. Damage taken and
+    /// turns taken don't count against the score.
+    pub fn score(&self) -> i64 {
+        let spells_cast: u32 = self.spells_cast.values().sum();
+
+        POINTS_PER_ROOM * self.rooms_explored as i64
+            + POINTS_PER_KILL * self.num_killed
+            + POINTS_PER_ITEM_LOOTED * self.items_looted as i64
+            + POINTS_PER_HIDDEN_COMPARTMENT * self.hidden_compartments_found as i64
+            + POINTS_PER_SPELL_CAST * spells_cast as i64
+            + self.total_damage_done as i64
+            + self.poison_damage_done as i64
+            + self.gold_earned as i64
+    }
+}
+
+/// The single number statistics, for anything that needs to pick one out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Statistic {
+    GoldEarned,
+    HiddenCompartmentsFound,
+    ItemsLooted,
+    NumKilled,
+    PoisonDamageDone,
+    RoomsExplored,
+    Score,
+    SpellsCast,
+    TotalDamageDone,
+    TotalDamageTaken,
+    TurnsTaken,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::components::spells::SpellName;
+
+    use super::Statistics;
+
+    #[test]
+    fn score_adds_up_every_statistic() {
+        let statistics = Statistics {
+            num_killed: 2,
+            total_damage_taken: 40,
+            total_damage_done: 30,
+            rooms_explored: 3,
+            kills_by_species: HashMap::new(),
+            spells_cast: HashMap::from([(SpellName::Heal, 2)]),
+            items_looted: 4,
+            gold_earned: 15,
+            hidden_compartments_found: 1,
+            poison_damage_done: 6,
+            turns_taken: 100,
+        };
+
+        assert_eq!(30 + 50 + 20 + 20 + 4 + 30 + 6 + 15, statistics.score());
+    }
 }
@@ -0,0 +1,38 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum VictoryCondition {
+    DefeatBoss,
+    Escape,
+    ReachDangerLevel,
+}
+
+/// Ways the current game can be won. With none of them set the game only
+/// ends when the player dies.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct VictoryConditions {
+    /// A way out is placed in the first room generated at this depth or deeper.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub escape_depth: Option<u32>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub target_danger_level: Option<u32>,
+    /// Killing any boss wins the game.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub defeat_boss: bool,
+}
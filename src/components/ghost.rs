@@ -11,4 +11,7 @@ use super::Character;
 pub struct Ghost {
     pub character: Character,
     pub name: Option<String>,
+    /// Name of the NPC that killed the player the ghost was.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub killer_name: Option<String>,
 }
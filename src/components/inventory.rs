@@ -7,8 +7,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::{
-    items::{CharacterItem, CharacterItemView, Item},
-    Attack, Defense,
+    items::{Affix, CharacterItem, CharacterItemView, Item, ItemType},
+    Attack, Defense, Material,
 };
 
 #[derive(Clone, Debug, Default)]
@@ -40,6 +40,12 @@ impl Inventory {
             .cloned()
     }
 
+    pub fn find_item_mut(&mut self, item_id: &Uuid) -> Option<&mut CharacterItem> {
+        self.equipment
+            .iter_mut()
+            .find(|character_item| character_item.item.id.eq(item_id))
+    }
+
     pub fn add_item(&mut self, character_item: CharacterItem) {
         self.equipment.push(character_item)
     }
@@ -74,6 +80,14 @@ impl Inventory {
             .collect()
     }
 
+    pub fn readied_defensive_items(&self) -> Vec<CharacterItem> {
+        self.equipment
+            .iter()
+            .filter(|item| item.item.defense.is_some() && item.is_at_the_ready())
+            .cloned()
+            .collect()
+    }
+
     pub fn non_readied_weapons(&self) -> Vec<&CharacterItem> {
         self.equipment
             .iter()
@@ -92,7 +106,7 @@ impl Inventory {
             .iter()
             .filter_map(|character_item| {
                 if character_item.at_the_ready {
-                    character_item.item.attack.clone()
+                    character_item.item.current_attack()
                 } else {
                     None
                 }
@@ -113,7 +127,7 @@ impl Inventory {
             .iter()
             .filter_map(|character_item| {
                 if character_item.at_the_ready {
-                    character_item.item.defense.clone()
+                    character_item.item.current_defense()
                 } else {
                     None
                 }
@@ -123,6 +137,37 @@ impl Inventory {
             })
     }
 
+    pub fn has_readied_affix(&self, affix: &Affix) -> bool {
+        self.equipment.iter().any(|character_item| {
+            character_item.at_the_ready && character_item.item.has_affix(affix)
+        })
+    }
+
+    /// Whether a cloak or anything made of fur is being worn.
+    pub fn has_warm_clothing(&self) -> bool {
+        self.equipment.iter().any(|character_item| {
+            character_item.at_the_ready
+                && character_item.is_wearable()
+                && (character_item.item.item_type == ItemType::Cloak
+                    || character_item.item.material == Some(Material::Fur))
+        })
+    }
+
+    pub fn magic_resistance(&self) -> i32 {
+        self.equipment
+            .iter()
+            .filter(|character_item| character_item.at_the_ready)
+            .map(|character_item| character_item.item.magic_resistance())
+            .sum()
+    }
+
+    pub fn total_weight(&self) -> i32 {
+        self.equipment
+            .iter()
+            .map(|character_item| character_item.item.weight())
+            .sum()
+    }
+
     pub fn drop_all(&mut self) -> Vec<Item> {
         let mut items: Vec<CharacterItem> = Vec::new();
         items.append(&mut self.equipment);
@@ -144,8 +189,8 @@ mod tests {
 
     use crate::components::{
         damage::AttackEffect,
-        items::{CharacterItem, Item, ItemType, LocationTag},
-        Attack, Defense,
+        items::{CharacterItem, Descriptor, Item, ItemType, LocationTag},
+        Attack, Defense, Material, Tag,
     };
 
     use super::Inventory;
@@ -170,6 +215,8 @@ mod tests {
                         defense: None,
                         consumable: None,
                         throwable: None,
+                        durability: None,
+                        affixes: Vec::new(),
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -190,6 +237,8 @@ mod tests {
                         defense: None,
                         consumable: None,
                         throwable: None,
+                        durability: None,
+                        affixes: Vec::new(),
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -223,6 +272,8 @@ mod tests {
                         defense: None,
                         consumable: None,
                         throwable: None,
+                        durability: None,
+                        affixes: Vec::new(),
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -243,6 +294,8 @@ mod tests {
                         defense: None,
                         consumable: None,
                         throwable: None,
+                        durability: None,
+                        affixes: Vec::new(),
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -279,6 +332,8 @@ mod tests {
                         }),
                         consumable: None,
                         throwable: None,
+                        durability: None,
+                        affixes: Vec::new(),
                     },
                     equipped_location: LocationTag::Feet,
                     at_the_ready: true,
@@ -297,6 +352,8 @@ mod tests {
                         }),
                         consumable: None,
                         throwable: None,
+                        durability: None,
+                        affixes: Vec::new(),
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -309,4 +366,100 @@ mod tests {
         let attack = merged.unwrap();
         assert_eq!(attack.damage_resistance, 8);
     }
+
+    #[test]
+    fn full_attack_with_descriptors() {
+        let inventory = Inventory {
+            equipment: vec![
+                CharacterItem {
+                    item: Item {
+                        id: Uuid::new_v4(),
+                        name: None,
+                        item_type: ItemType::Dagger,
+                        tags: Vec::new(),
+                        descriptors: vec![Descriptor::Keen],
+                        material: None,
+                        attack: Some(Attack {
+                            num_rolls: 1,
+                            modifier: 0,
+                            effects: Vec::new(),
+                        }),
+                        defense: None,
+                        consumable: None,
+                        throwable: None,
+                        durability: None,
+                        affixes: Vec::new(),
+                    },
+                    equipped_location: LocationTag::Hand,
+                    at_the_ready: true,
+                },
+                CharacterItem {
+                    item: Item {
+                        id: Uuid::new_v4(),
+                        name: None,
+                        item_type: ItemType::Club,
+                        tags: Vec::new(),
+                        descriptors: vec![Descriptor::Rusty, Descriptor::Chipped],
+                        material: None,
+                        attack: Some(Attack {
+                            num_rolls: 2,
+                            modifier: 1,
+                            effects: Vec::new(),
+                        }),
+                        defense: None,
+                        consumable: None,
+                        throwable: None,
+                        durability: None,
+                        affixes: Vec::new(),
+                    },
+                    equipped_location: LocationTag::Hand,
+                    at_the_ready: true,
+                },
+            ],
+        };
+
+        let attack = inventory.full_attack().unwrap();
+        assert_eq!(attack.num_rolls, 3);
+        assert_eq!(attack.modifier, 0);
+    }
+
+    #[test]
+    fn only_worn_cloaks_and_furs_are_warm() {
+        let item = |item_type: ItemType, material: Option<Material>| Item {
+            id: Uuid::new_v4(),
+            name: None,
+            item_type,
+            tags: vec![Tag::Clothing, Tag::Wearable, Tag::Equippable],
+            descriptors: Vec::new(),
+            material,
+            attack: None,
+            defense: None,
+            consumable: None,
+            throwable: None,
+            durability: None,
+            affixes: Vec::new(),
+        };
+        let mut inventory = Inventory {
+            equipment: vec![
+                CharacterItem {
+                    item: item(ItemType::Vest, Some(Material::Leather)),
+                    equipped_location: LocationTag::Body,
+                    at_the_ready: true,
+                },
+                CharacterItem {
+                    item: item(ItemType::Cloak, Some(Material::Wool)),
+                    equipped_location: LocationTag::Packed,
+                    at_the_ready: false,
+                },
+            ],
+        };
+        assert!(!inventory.has_warm_clothing());
+
+        inventory.equipment[1].at_the_ready = true;
+        assert!(inventory.has_warm_clothing());
+
+        inventory.equipment.remove(1);
+        inventory.equipment[0].item.material = Some(Material::Fur);
+        assert!(inventory.has_warm_clothing());
+    }
 }
@@ -0,0 +1,68 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::components::damage::AttackEffect;
+
+/// Magic bound into an item. Enchantments help whoever has the item at the ready,
+/// curses hurt them.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Affix {
+    /// Adds to the attack modifier.
+    Empowered,
+    /// Adds to damage resistance.
+    Warded,
+    /// Attacks burn with acid.
+    Corrosive,
+    /// Attacks poison.
+    Venomous,
+    /// Hits heal the wielder.
+    LifeStealing,
+    /// Heals the wearer a little every turn.
+    Regenerating,
+    /// Can't be unreadied once at the ready.
+    Binding,
+    /// Slowly poisons the wearer every turn.
+    Festering,
+    /// Draws the attention of every NPC in the room.
+    Beckoning,
+}
+
+impl Affix {
+    pub fn is_curse(&self) -> bool {
+        matches!(*self, Affix::Binding | Affix::Festering | Affix::Beckoning)
+    }
+
+    pub fn attack_modifier(&self) -> i32 {
+        match *self {
+            Affix::Empowered => 2,
+            _ => 0,
+        }
+    }
+
+    pub fn defense_modifier(&self) -> i32 {
+        match *self {
+            Affix::Warded => 2,
+            _ => 0,
+        }
+    }
+
+    pub fn attack_effect(&self) -> Option<AttackEffect> {
+        match *self {
+            Affix::Corrosive => Some(AttackEffect::Acidic),
+            Affix::Venomous => Some(AttackEffect::Toxic),
+            _ => None,
+        }
+    }
+}
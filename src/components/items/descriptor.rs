@@ -6,6 +6,8 @@ use poem_openapi::Enum;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
+use crate::components::damage::AttackEffect;
+
 #[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
@@ -51,3 +53,123 @@ pub enum Descriptor {
     WaterLogged,
     Weathered,
 }
+
+impl Descriptor {
+    /// Change to an item's attack modifier while it carries this descriptor.
+    pub fn attack_modifier(&self) -> i32 {
+        match *self {
+            Descriptor::Keen | Descriptor::Quality => 1,
+            Descriptor::Chipped
+            | Descriptor::Cracked
+            | Descriptor::Dull
+            | Descriptor::Rusty
+            | Descriptor::Splintered
+            | Descriptor::Tangled => -1,
+            Descriptor::Broken => -2,
+            _ => 0,
+        }
+    }
+
+    /// Change to an item's damage resistance while it carries this descriptor.
+    pub fn defense_modifier(&self) -> i32 {
+        match *self {
+            Descriptor::Heavy | Descriptor::Quality => 1,
+            Descriptor::Cracked
+            | Descriptor::Crumbling
+            | Descriptor::Ripped
+            | Descriptor::Rotting
+            | Descriptor::Torn => -1,
+            Descriptor::Broken => -2,
+            _ => 0,
+        }
+    }
+
+    /// Effect added to an item's attack when it is generated with this descriptor.
+    pub fn attack_effect(&self) -> Option<AttackEffect> {
+        match *self {
+            Descriptor::Keen => Some(AttackEffect::Sharp),
+            Descriptor::Heavy => Some(AttackEffect::Crushing),
+            _ => None,
+        }
+    }
+
+    /// Damage taken from spells is reduced by this much.
+    pub fn magic_resistance(&self) -> i32 {
+        match *self {
+            Descriptor::Shimmering => 2,
+            _ => 0,
+        }
+    }
+
+    /// Percentage of its durability an item starts with because of this descriptor.
+    pub fn durability_percent(&self) -> i32 {
+        match *self {
+            Descriptor::Broken => 10,
+            Descriptor::Chipped
+            | Descriptor::Cracked
+            | Descriptor::Crumbling
+            | Descriptor::Rotting
+            | Descriptor::Rusty
+            | Descriptor::Splintered => 50,
+            Descriptor::Beaten
+            | Descriptor::Moldy
+            | Descriptor::Ripped
+            | Descriptor::Scuffed
+            | Descriptor::Tarnished
+            | Descriptor::Torn
+            | Descriptor::WaterLogged
+            | Descriptor::Weathered => 75,
+            Descriptor::Bleached
+            | Descriptor::Bloodstained
+            | Descriptor::Colourful
+            | Descriptor::Dingy
+            | Descriptor::Dirty
+            | Descriptor::Drab
+            | Descriptor::Dull
+            | Descriptor::Heavy
+            | Descriptor::Keen
+            | Descriptor::Quality
+            | Descriptor::Shimmering
+            | Descriptor::Shiny
+            | Descriptor::Smoothed
+            | Descriptor::Soiled
+            | Descriptor::Stained
+            | Descriptor::Tangled => 100,
+        }
+    }
+
+    /// Percentage applied to an item's weight because of this descriptor.
+    pub fn weight_percent(&self) -> i32 {
+        match *self {
+            Descriptor::Heavy => 150,
+            Descriptor::WaterLogged => 125,
+            Descriptor::Broken | Descriptor::Crumbling | Descriptor::Splintered => 90,
+            Descriptor::Beaten
+            | Descriptor::Bleached
+            | Descriptor::Bloodstained
+            | Descriptor::Chipped
+            | Descriptor::Colourful
+            | Descriptor::Cracked
+            | Descriptor::Dingy
+            | Descriptor::Dirty
+            | Descriptor::Drab
+            | Descriptor::Dull
+            | Descriptor::Keen
+            | Descriptor::Moldy
+            | Descriptor::Quality
+            | Descriptor::Ripped
+            | Descriptor::Rotting
+            | Descriptor::Rusty
+            | Descriptor::Scuffed
+            | Descriptor::Shimmering
+            | Descriptor::Shiny
+            | Descriptor::Smoothed
+            | Descriptor::Soiled
+            | Descriptor::Stained
+            | Descriptor::Tangled
+            | Descriptor::Tarnished
+            | Descriptor::Torn
+            | Descriptor::Weathered => 100,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+const WORN_PERCENT: i32 = 25;
+const DURABILITY_PER_GOLD: u32 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct Durability {
+    pub current: i32,
+    pub max: i32,
+}
+
+impl Durability {
+    pub fn is_broken(&self) -> bool {
+        self.current <= 0
+    }
+
+    /// Worn items still work, just not as well as they should.
+    pub fn is_worn(&self) -> bool {
+        self.current * 100 <= self.max * WORN_PERCENT
+    }
+
+    pub fn needs_repair(&self) -> bool {
+        self.current < self.max
+    }
+
+    pub fn wear(&mut self, amount: i32) {
+        self.current = (self.current - amount).max(0);
+    }
+
+    /// Gold it takes to bring the item back to full durability.
+    pub fn repair_cost(&self) -> u32 {
+        let missing = (self.max - self.current).max(0) as u32;
+        missing.div_ceil(DURABILITY_PER_GOLD)
+    }
+
+    pub fn repair(&mut self) {
+        self.current = self.max;
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use super::{item_type::ItemType, location_tag::LocationTag};
+
+/// Where an item sits on a body location. Only one item can take up a layer of a
+/// location, so a Shirt can go under a Breastplate but not under another Shirt.
+/// Held items share the hands instead of a layer.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum EquipLayer {
+    Under,
+    Worn,
+    Over,
+    Held,
+}
+
+pub const HANDS_AVAILABLE: usize = 2;
+
+pub fn equip_layer_for_item_type(item_type: &ItemType) -> EquipLayer {
+    match *item_type {
+        ItemType::Buckler
+        | ItemType::Club
+        | ItemType::Dagger
+        | ItemType::Dirk
+        | ItemType::GreatSword
+        | ItemType::Halberd
+        | ItemType::Hammer
+        | ItemType::LongSword
+        | ItemType::Mace
+        | ItemType::Morningstar
+        | ItemType::Pike
+        | ItemType::Shield
+        | ItemType::ShortSword
+        | ItemType::Spear
+        | ItemType::Whip => EquipLayer::Held,
+        ItemType::LoinCloth | ItemType::Mask | ItemType::Shirt => EquipLayer::Under,
+        ItemType::Breastplate => EquipLayer::Over,
+        ItemType::Boots
+        | ItemType::BowlerHat
+        | ItemType::Cloak
+        | ItemType::Crown
+        | ItemType::Fedora
+        | ItemType::Flask
+        | ItemType::Gloves
+        | ItemType::Helm
+        | ItemType::Key
+        | ItemType::PlateBoots
+        | ItemType::PlateGauntlets
+        | ItemType::PlateHelmet
+        | ItemType::Pot
+        | ItemType::Scroll
+        | ItemType::Shackles
+        | ItemType::TopHat
+        | ItemType::Trousers
+        | ItemType::Vest => EquipLayer::Worn,
+    }
+}
+
+pub fn hands_needed_for_item_type(item_type: &ItemType) -> usize {
+    if item_type.is_two_handed() {
+        HANDS_AVAILABLE
+    } else if equip_layer_for_item_type(item_type) == EquipLayer::Held {
+        1
+    } else {
+        0
+    }
+}
+
+/// Whether an item at the ready in one location and layer takes the spot another
+/// item wants. Held items are never in conflict here, they are limited by hands.
+pub fn slots_conflict(
+    location: &LocationTag,
+    item_type: &ItemType,
+    other_location: &LocationTag,
+    other_item_type: &ItemType,
+) -> bool {
+    let layer = equip_layer_for_item_type(item_type);
+    if layer == EquipLayer::Held || location.hides_full_item() {
+        return false;
+    }
+
+    location == other_location && layer == equip_layer_for_item_type(other_item_type)
+}
@@ -7,13 +7,16 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::components::{
+    damage::AttackEffect,
     Material, Tag, {Attack, Defense},
 };
 
 use super::{
-    Descriptor, ItemType, Throwable, ThrowableView, {Consumable, ConsumableView},
+    Affix, Descriptor, Durability, ItemType, Throwable, ThrowableView, {Consumable, ConsumableView},
 };
 
+const WORN_PENALTY: i32 = 1;
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
@@ -39,6 +42,10 @@ pub struct Item {
     pub consumable: Option<Consumable>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub throwable: Option<Throwable>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub durability: Option<Durability>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub affixes: Vec<Affix>,
 }
 
 impl Item {
@@ -65,6 +72,152 @@ impl Item {
         self.tags.iter().any(|tag| tag.is_consumable())
     }
 
+    pub fn has_affix(&self, affix: &Affix) -> bool {
+        self.affixes.contains(affix)
+    }
+
+    pub fn is_cursed(&self) -> bool {
+        self.affixes.iter().any(|affix| affix.is_curse())
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.durability
+            .as_ref()
+            .map(|durability| durability.is_broken())
+            .unwrap_or_default()
+    }
+
+    pub fn is_worn(&self) -> bool {
+        self.durability
+            .as_ref()
+            .map(|durability| durability.is_worn())
+            .unwrap_or_default()
+    }
+
+    pub fn wear(&mut self, amount: i32) {
+        if let Some(durability) = self.durability.as_mut() {
+            durability.wear(amount);
+        }
+    }
+
+    /// Broken items stay broken, and look it, until they are repaired.
+    pub fn break_item(&mut self) {
+        if let Some(durability) = self.durability.as_mut() {
+            durability.current = 0;
+        }
+
+        if !self.descriptors.contains(&Descriptor::Broken) {
+            self.descriptors.push(Descriptor::Broken);
+        }
+    }
+
+    pub fn repair(&mut self) {
+        if let Some(durability) = self.durability.as_mut() {
+            durability.repair();
+        }
+
+        self.descriptors
+            .retain(|descriptor| !matches!(descriptor, Descriptor::Broken));
+    }
+
+    /// The attack the item gives in its current condition. Broken items give
+    /// nothing and worn ones lose some of their edge. Descriptors like Keen or
+    /// Rusty nudge the modifier.
+    pub fn current_attack(&self) -> Option<Attack> {
+        if self.is_broken() {
+            return None;
+        }
+
+        self.attack.clone().map(|attack| {
+            let worn_penalty = if self.is_worn() { WORN_PENALTY } else { 0 };
+            let descriptor_modifier: i32 = self
+                .descriptors
+                .iter()
+                .map(|descriptor| descriptor.attack_modifier())
+                .sum();
+            let affix_modifier: i32 = self
+                .affixes
+                .iter()
+                .map(|affix| affix.attack_modifier())
+                .sum();
+
+            let mut effects: Vec<AttackEffect> = attack
+                .effects
+                .into_iter()
+                .chain(
+                    self.affixes
+                        .iter()
+                        .filter_map(|affix| affix.attack_effect()),
+                )
+                .collect();
+            effects.sort();
+            effects.dedup();
+
+            Attack {
+                num_rolls: attack.num_rolls,
+                modifier: attack.modifier + descriptor_modifier + affix_modifier - worn_penalty,
+                effects,
+            }
+        })
+    }
+
+    pub fn current_defense(&self) -> Option<Defense> {
+        if self.is_broken() {
+            return None;
+        }
+
+        self.defense.clone().map(|defense| {
+            let worn_penalty = if self.is_worn() { WORN_PENALTY } else { 0 };
+            let descriptor_modifier: i32 = self
+                .descriptors
+                .iter()
+                .map(|descriptor| descriptor.defense_modifier())
+                .sum::<i32>()
+                + self
+                    .affixes
+                    .iter()
+                    .map(|affix| affix.defense_modifier())
+                    .sum::<i32>();
+
+            Defense {
+                damage_resistance: (defense.damage_resistance + descriptor_modifier - worn_penalty)
+                    .max(0),
+            }
+        })
+    }
+
+    pub fn magic_resistance(&self) -> i32 {
+        if self.is_broken() {
+            return 0;
+        }
+
+        self.descriptors
+            .iter()
+            .map(|descriptor| descriptor.magic_resistance())
+            .sum()
+    }
+
+    pub fn weight(&self) -> i32 {
+        let base_weight = self.item_type.weight();
+        if base_weight == 0 {
+            return 0;
+        }
+
+        let material_weight = match &self.material {
+            Some(material) => base_weight * material.weight_percent() / 100,
+            None => base_weight,
+        };
+
+        let weight = self
+            .descriptors
+            .iter()
+            .fold(material_weight, |weight, descriptor| {
+                weight * descriptor.weight_percent() / 100
+            });
+
+        weight.max(1)
+    }
+
     pub fn decrease_uses(&mut self) {
         if let Some(mut consumable) = self.consumable.as_mut() {
             consumable.uses -= 1;
@@ -103,6 +256,14 @@ pub struct ItemView {
     pub knows_consumable: bool,
     pub throwable: Option<ThrowableView>,
     pub is_equippable: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub weight: i32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub durability: Option<Durability>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub affixes: Vec<Affix>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub affixes_known: bool,
 }
 
 impl ItemView {
@@ -114,3 +275,26 @@ impl ItemView {
         self.tags.iter().any(|tag| tag.is_wearable())
     }
 }
+
+#[derive(Clone, Debug, Default)]
+pub struct ItemViewArgs {
+    pub knows_attack: bool,
+    pub knows_defense: bool,
+    pub knows_consumable: bool,
+    pub knows_affixes: bool,
+    /// The player knows what their own gear looks like and how well it fights,
+    /// but not what a scroll or grog does or what an item is enchanted with.
+    pub carried_by_player: bool,
+}
+
+impl ItemViewArgs {
+    pub fn knows_all_args() -> ItemViewArgs {
+        ItemViewArgs {
+            knows_attack: true,
+            knows_defense: true,
+            knows_consumable: true,
+            knows_affixes: true,
+            carried_by_player: false,
+        }
+    }
+}
@@ -33,6 +33,7 @@ pub enum ItemType {
     Halberd,
     Hammer,
     Helm,
+    Key,
     LoinCloth,
     LongSword,
     Mace,
@@ -78,6 +79,43 @@ impl ItemType {
         matches!(*self, ItemType::Boots | ItemType::PlateBoots)
     }
 
+    /// Base weight of the item type, before material and descriptors are considered.
+    pub fn weight(&self) -> i32 {
+        match *self {
+            ItemType::Scroll => 0,
+            ItemType::BowlerHat
+            | ItemType::Crown
+            | ItemType::Dagger
+            | ItemType::Dirk
+            | ItemType::Fedora
+            | ItemType::Flask
+            | ItemType::Gloves
+            | ItemType::Key
+            | ItemType::LoinCloth
+            | ItemType::Mask
+            | ItemType::TopHat => 1,
+            ItemType::Pot | ItemType::Shirt | ItemType::Trousers | ItemType::Vest => 2,
+            ItemType::Boots | ItemType::Cloak | ItemType::ShortSword | ItemType::Whip => 3,
+            ItemType::Club
+            | ItemType::Helm
+            | ItemType::LongSword
+            | ItemType::PlateGauntlets
+            | ItemType::Shackles => 4,
+            ItemType::Buckler | ItemType::Hammer | ItemType::Mace | ItemType::Spear => 5,
+            ItemType::Morningstar | ItemType::PlateHelmet => 6,
+            ItemType::GreatSword | ItemType::Pike | ItemType::PlateBoots => 8,
+            ItemType::Halberd | ItemType::Shield => 9,
+            ItemType::Breastplate => 15,
+        }
+    }
+
+    pub fn is_two_handed(&self) -> bool {
+        matches!(
+            *self,
+            ItemType::GreatSword | ItemType::Halberd | ItemType::Pike
+        )
+    }
+
     pub fn is_for_hands(&self) -> bool {
         matches!(
             *self,
@@ -137,6 +175,7 @@ impl Tagged for ItemType {
             ItemType::Scroll => vec![Tag::Consumable, Tag::Teachable],
             ItemType::Pot => vec![Tag::Consumable, Tag::Throwable],
             ItemType::Flask => vec![Tag::Consumable],
+            ItemType::Key => vec![Tag::Key],
         }
     }
 }
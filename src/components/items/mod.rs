@@ -1,7 +1,10 @@
+mod affix;
 mod character_item;
 mod consumable;
 mod consumable_effect;
 mod descriptor;
+mod durability;
+mod equip_slot;
 mod fixture_item;
 mod item;
 mod item_type;
@@ -10,6 +13,7 @@ mod location_tag;
 mod throwable;
 mod throwable_effect;
 
+pub use affix::{Affix, AffixIter};
 pub use character_item::{CharacterItem, CharacterItemView};
 pub use consumable::{Consumable, ConsumableView};
 pub use consumable_effect::{
@@ -17,8 +21,13 @@ pub use consumable_effect::{
     LearnSpellEffect,
 };
 pub use descriptor::{Descriptor, DescriptorIter};
+pub use durability::Durability;
+pub use equip_slot::{
+    equip_layer_for_item_type, hands_needed_for_item_type, slots_conflict, EquipLayer,
+    EquipLayerIter, HANDS_AVAILABLE,
+};
 pub use fixture_item::{FixtureItem, FixtureItemView};
-pub use item::{Item, ItemView};
+pub use item::{Item, ItemView, ItemViewArgs};
 pub use item_type::{ItemType, ItemTypeIter};
 pub use location_descriptor::{LocationDescriptor, LocationDescriptorIter};
 pub use location_tag::{
@@ -0,0 +1,17 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct Lock {
+    pub locked: bool,
+    /// Total a lockpicking roll needs to reach to open the lock without its key.
+    pub difficulty: i32,
+    /// The key item made for this lock, if there is one.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub key_id: Option<Uuid>,
+}
@@ -36,6 +36,39 @@ pub enum Material {
     Wool,
 }
 
+impl Material {
+    /// Percentage applied to an item's base weight when built with this material.
+    pub fn weight_percent(&self) -> i32 {
+        match *self {
+            Material::Paper | Material::Papyrus => 20,
+            Material::Cotton | Material::Linen | Material::Silk => 40,
+            Material::Wool => 50,
+            Material::Fur => 60,
+            Material::Glass | Material::Hide | Material::Leather => 70,
+            Material::Ceramic | Material::Wooden => 80,
+            Material::Bone => 90,
+            Material::Iron | Material::Steel => 150,
+            Material::Stone => 170,
+            Material::Gold => 180,
+        }
+    }
+
+    /// How much wear an item built with this material can take before breaking.
+    pub fn durability(&self) -> i32 {
+        match *self {
+            Material::Paper | Material::Papyrus => 5,
+            Material::Ceramic | Material::Glass => 8,
+            Material::Cotton | Material::Linen | Material::Silk => 10,
+            Material::Fur | Material::Wool => 15,
+            Material::Gold | Material::Wooden => 20,
+            Material::Bone | Material::Hide | Material::Leather => 25,
+            Material::Stone => 30,
+            Material::Iron => 35,
+            Material::Steel => 40,
+        }
+    }
+}
+
 pub trait BuiltWithMaterial {
     fn possible_materials(&self) -> Vec<Material>;
 }
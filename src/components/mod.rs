@@ -1,3 +1,5 @@
+pub mod achievements;
+pub mod attributes;
 mod character;
 pub mod damage;
 mod effects;
@@ -7,27 +9,35 @@ mod ghost;
 mod inventory;
 pub mod items;
 mod life_modifier;
+mod lock;
 mod material;
 mod non_player;
 mod player;
+pub mod quests;
+mod rank;
 pub mod rooms;
 mod size;
 mod species;
 pub mod spells;
 mod stats;
 mod tag;
+mod trap;
 pub mod worlds;
 
+pub use attributes::Attributes;
 pub use character::{Character, CharacterView, CharacterViewArgs};
 pub use damage::{Attack, Defense, Health};
 pub use effects::{Effects, EffectsView, Poison};
 pub use ghost::Ghost;
 pub use inventory::{Inventory, InventoryView};
 pub use life_modifier::LifeModifier;
+pub use lock::Lock;
 pub use material::{BuiltWithMaterial, Material, MaterialIter};
 pub use non_player::{NonPlayer, NonPlayerView, NonPlayerViewArgs};
 pub use player::{PlayerCharacter, PlayerCharacterView};
+pub use rank::{Rank, RankIter};
 pub use size::Size;
 pub use species::{Species, SpeciesIter};
 pub use stats::{Stats, StatsView};
 pub use tag::{Tag, Tagged};
+pub use trap::{Trap, TrapType, TrapTypeIter, TrapView};
@@ -6,7 +6,7 @@ use poem_openapi::Object;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::{Character, CharacterView, CharacterViewArgs};
+use super::{Character, CharacterView, CharacterViewArgs, Rank};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -15,6 +15,11 @@ pub struct NonPlayer {
     pub character: Character,
     pub id: Uuid,
     pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub rank: Option<Rank>,
+    /// Elites and bosses are known by a title as well as their name.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub title: Option<String>,
 }
 
 impl NonPlayer {
@@ -25,6 +30,10 @@ impl NonPlayer {
     pub fn kill(&mut self) {
         self.character.kill();
     }
+
+    pub fn is_boss(&self) -> bool {
+        self.rank == Some(Rank::Boss)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -36,6 +45,10 @@ pub struct NonPlayerView {
     pub can_be_looted: bool,
     pub id: String,
     pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub rank: Option<Rank>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub title: Option<String>,
 }
 
 #[derive(Clone, Debug, Default)]
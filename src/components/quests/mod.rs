@@ -0,0 +1,7 @@
+mod objective;
+mod quest;
+mod reward;
+
+pub use objective::Objective;
+pub use quest::{Quest, QuestView};
+pub use reward::Reward;
@@ -0,0 +1,41 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::components::{NonPlayer, Species};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Objective {
+    /// Kill a number of NPCs of one species, anywhere.
+    KillSpecies { species: Species, count: u32 },
+    /// Take a named item out of the fixture it was seen in.
+    RetrieveItem {
+        item_id: Uuid,
+        item_name: String,
+        fixture_id: Uuid,
+    },
+    /// Get down to a floor.
+    ReachDepth { depth: u32 },
+    /// Bring someone down to a floor. They follow the player from room to room,
+    /// and anything hostile in the room can turn on them. The quest fails if they die.
+    EscortNpc { npc: Box<NonPlayer>, depth: u32 },
+}
+
+impl Objective {
+    /// The progress needed for the objective to be met.
+    pub fn goal(&self) -> u32 {
+        match self {
+            Objective::KillSpecies { count, .. } => *count,
+            Objective::RetrieveItem { .. } => 1,
+            Objective::ReachDepth { depth } | Objective::EscortNpc { depth, .. } => *depth,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::components::NonPlayer;
+
+use super::{Objective, Reward};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct Quest {
+    pub id: Uuid,
+    pub objective: Objective,
+    pub reward: Reward,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub progress: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub completed: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub failed: bool,
+}
+
+impl Quest {
+    /// The NPC being escorted, while the quest is to escort one.
+    pub fn escort_mut(&mut self) -> Option<&mut NonPlayer> {
+        match &mut self.objective {
+            Objective::EscortNpc { npc, .. } => Some(npc.as_mut()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Quest"))]
+pub struct QuestView {
+    pub id: String,
+    pub description: String,
+    pub progress: u32,
+    pub goal: u32,
+    pub completed: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub failed: bool,
+    pub reward: String,
+}
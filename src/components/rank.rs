@@ -0,0 +1,64 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// NPCs that stand out from the rest of their group.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Rank {
+    /// Leads a group, with better stats and an enchanted item. Elites are
+    /// seasoned fighters and dodge blows more often.
+    Elite,
+    /// Rules a room. Bosses are tougher still and regenerate every turn.
+    Boss,
+}
+
+impl Rank {
+    pub fn health_multiplier(&self) -> i32 {
+        match *self {
+            Rank::Elite => 2,
+            Rank::Boss => 3,
+        }
+    }
+
+    pub fn attribute_bonus(&self) -> i32 {
+        match *self {
+            Rank::Elite => 2,
+            Rank::Boss => 4,
+        }
+    }
+
+    /// How many of their items are guaranteed to be enchanted.
+    pub fn num_enchanted_items(&self) -> usize {
+        match *self {
+            Rank::Elite => 1,
+            Rank::Boss => 2,
+        }
+    }
+
+    /// Extra chance, in percent, of dodging the player's attacks.
+    pub fn dodge_chance(&self) -> i32 {
+        match *self {
+            Rank::Elite => 15,
+            Rank::Boss => 0,
+        }
+    }
+
+    /// Health healed at the end of every turn.
+    pub fn regeneration(&self) -> i32 {
+        match *self {
+            Rank::Elite => 0,
+            Rank::Boss => 2,
+        }
+    }
+}
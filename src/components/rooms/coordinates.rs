@@ -0,0 +1,44 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use super::Direction;
+
+/// Position of a room on the grid of its floor. North is negative y and east is positive x.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct Coordinates {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coordinates {
+    /// The cell on the other side of a wall. Vertical directions stay on the
+    /// same cell of the floor above or below.
+    pub fn neighbour(&self, direction: &Direction) -> Coordinates {
+        match *direction {
+            Direction::North => Coordinates {
+                x: self.x,
+                y: self.y - 1,
+            },
+            Direction::East => Coordinates {
+                x: self.x + 1,
+                y: self.y,
+            },
+            Direction::South => Coordinates {
+                x: self.x,
+                y: self.y + 1,
+            },
+            Direction::West => Coordinates {
+                x: self.x - 1,
+                y: self.y,
+            },
+            Direction::Up | Direction::Down => *self,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use super::ExitType;
+
+/// The wall of a room an exit sits in, or the floor or ceiling for exits
+/// that change depth.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+    Up,
+    Down,
+}
+
+impl Direction {
+    pub fn flat() -> Vec<Direction> {
+        vec![
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ]
+    }
+
+    pub fn opposite(&self) -> Direction {
+        match *self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(*self, Direction::Up | Direction::Down)
+    }
+
+    /// Exits that change depth always go through the floor or ceiling.
+    pub fn for_exit_type(exit_type: &ExitType) -> Option<Direction> {
+        match exit_type.depth_change() {
+            change if change > 0 => Some(Direction::Down),
+            change if change < 0 => Some(Direction::Up),
+            _ if *exit_type == ExitType::HoleInTheCeiling => Some(Direction::Up),
+            _ => None,
+        }
+    }
+}
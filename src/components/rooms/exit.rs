@@ -7,9 +7,9 @@ use poem_openapi::Object;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::components::{Material, Size};
+use crate::components::{Lock, Material, Size, Trap, TrapView};
 
-use super::{ExitDescriptor, ExitType};
+use super::{Direction, ExitDescriptor, ExitType};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -21,6 +21,18 @@ pub struct Exit {
     pub material: Option<Material>,
     pub descriptors: Vec<ExitDescriptor>,
     pub size: Option<Size>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub direction: Option<Direction>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub lock: Option<Lock>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub trap: Option<Trap>,
+}
+
+impl Exit {
+    pub fn is_locked(&self) -> bool {
+        self.lock.as_ref().is_some_and(|lock| lock.locked)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -35,4 +47,11 @@ pub struct ExitView {
     pub descriptors: Vec<ExitDescriptor>,
     pub size: Option<Size>,
     pub has_visited_connected_room: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub direction: Option<Direction>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_locked: bool,
+    /// A tripwire on the exit, once the player knows about it.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub trap: Option<TrapView>,
 }
@@ -18,9 +18,55 @@ pub enum ExitType {
     Door,
     DugOutTunnelEntrance,
     Hallway,
+    HoleInTheCeiling,
     HoleInTheFloor,
     HoleInTheWall,
     OpeningToTheVoid,
     StaircaseDown,
     StaircaseUp,
+    /// Leads out of the underworld altogether. Only placed when the game can be won by escaping.
+    WayOut,
+}
+
+impl ExitType {
+    /// How many floors deeper the exit leads.
+    pub fn depth_change(&self) -> i32 {
+        match *self {
+            ExitType::HoleInTheFloor | ExitType::StaircaseDown => 1,
+            ExitType::StaircaseUp => -1,
+            ExitType::Door
+            | ExitType::DugOutTunnelEntrance
+            | ExitType::Hallway
+            | ExitType::HoleInTheCeiling
+            | ExitType::HoleInTheWall
+            | ExitType::OpeningToTheVoid
+            | ExitType::WayOut => 0,
+        }
+    }
+
+    /// What the same exit looks like from the room on its other side.
+    pub fn other_side(&self) -> ExitType {
+        match *self {
+            ExitType::HoleInTheFloor => ExitType::HoleInTheCeiling,
+            ExitType::HoleInTheCeiling => ExitType::HoleInTheFloor,
+            ExitType::StaircaseDown => ExitType::StaircaseUp,
+            ExitType::StaircaseUp => ExitType::StaircaseDown,
+            ExitType::Door
+            | ExitType::DugOutTunnelEntrance
+            | ExitType::Hallway
+            | ExitType::HoleInTheWall
+            | ExitType::OpeningToTheVoid
+            | ExitType::WayOut => *self,
+        }
+    }
+
+    /// Holes can be dropped through but there is no climbing back up.
+    pub fn can_be_used(&self) -> bool {
+        !matches!(*self, ExitType::HoleInTheCeiling)
+    }
+
+    /// Exits that only make sense as the other side of another exit are never rolled.
+    pub fn can_be_generated(&self) -> bool {
+        !matches!(*self, ExitType::HoleInTheCeiling | ExitType::WayOut)
+    }
 }
@@ -1,5 +1,7 @@
+pub mod coordinates;
 pub mod descriptor;
 pub mod dimensions;
+pub mod direction;
 pub mod exit;
 pub mod exit_descriptor;
 pub mod exit_type;
@@ -12,8 +14,10 @@ pub mod room;
 pub mod room_type;
 pub mod room_view;
 
+pub use coordinates::Coordinates;
 pub use descriptor::{Descriptor, DescriptorIter};
 pub use dimensions::Dimensions;
+pub use direction::{Direction, DirectionIter};
 pub use exit::{Exit, ExitView};
 pub use exit_descriptor::ExitDescriptor;
 pub use exit_type::{ExitType, ExitTypeIter};
@@ -4,9 +4,11 @@ use bevy_ecs::prelude::Component;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::components::{items::Item, NonPlayer};
+use crate::components::{items::Item, NonPlayer, Trap};
 
-use super::{Descriptor, Dimensions, Exit, FixturePosition, Flavour, NpcPosition, RoomType};
+use super::{
+    Coordinates, Descriptor, Dimensions, Exit, FixturePosition, Flavour, NpcPosition, RoomType,
+};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -29,9 +31,60 @@ pub struct Room {
     pub exits: Vec<Exit>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub loose_items: Vec<Item>,
+    /// Which floor the room is on, with 0 being the floor the game starts on.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub depth: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub coordinates: Coordinates,
+    /// Pressure plates in the floor.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub traps: Vec<Trap>,
 }
 
 impl Room {
+    /// Any trap in the room, whether it's in the floor, on an exit or on a fixture.
+    pub fn find_trap(&self, trap_id: &Uuid) -> Option<&Trap> {
+        self.traps
+            .iter()
+            .chain(self.exits.iter().filter_map(|exit| exit.trap.as_ref()))
+            .chain(
+                self.fixture_positions
+                    .iter()
+                    .filter_map(|position| position.fixture.trap.as_ref()),
+            )
+            .find(|trap| trap.id.eq(trap_id))
+    }
+
+    pub fn find_trap_mut(&mut self, trap_id: &Uuid) -> Option<&mut Trap> {
+        self.traps
+            .iter_mut()
+            .chain(self.exits.iter_mut().filter_map(|exit| exit.trap.as_mut()))
+            .chain(
+                self.fixture_positions
+                    .iter_mut()
+                    .filter_map(|position| position.fixture.trap.as_mut()),
+            )
+            .find(|trap| trap.id.eq(trap_id))
+    }
+
+    pub fn find_fixture_with_trap(&self, trap_id: &Uuid) -> Option<&FixturePosition> {
+        self.fixture_positions.iter().find(|position| {
+            position
+                .fixture
+                .trap
+                .as_ref()
+                .is_some_and(|trap| trap.id.eq(trap_id))
+        })
+    }
+
+    pub fn has_descriptor(&self, descriptor: &Descriptor) -> bool {
+        self.descriptors.contains(descriptor)
+    }
+
+    pub fn find_exit(&self, exit_id: &Uuid) -> Option<&Exit> {
+        self.exits.iter().find(|exit| exit.id.eq(exit_id))
+    }
+
     pub fn find_npc(&self, npc_id: &Uuid) -> Option<&NonPlayer> {
         self.npc_positions
             .iter()
@@ -45,6 +98,13 @@ impl Room {
             .find(|fixture_position| fixture_position.fixture.id.eq(fixture_id))
     }
 
+    pub fn living_boss(&self) -> Option<&NonPlayer> {
+        self.npc_positions
+            .iter()
+            .map(|position| &position.npc)
+            .find(|npc| npc.is_boss() && !npc.character.is_dead())
+    }
+
     pub fn first_alive_npc(&self) -> Option<&NonPlayer> {
         self.npc_positions
             .iter()
@@ -5,10 +5,11 @@ use poem_openapi::Object;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
 
-use crate::components::items::ItemView;
+use crate::components::{items::ItemView, TrapView};
 
 use super::{
-    Descriptor, Dimensions, ExitView, FixturePositionView, Flavour, NpcPositionView, RoomType,
+    Coordinates, Descriptor, Dimensions, ExitView, FixturePositionView, Flavour, NpcPositionView,
+    RoomType,
 };
 
 #[derive(Clone, Debug)]
@@ -26,6 +27,13 @@ pub struct RoomView {
     pub flavour: Option<Flavour>,
     pub exits: Vec<ExitView>,
     pub loose_items: Vec<ItemView>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub depth: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub coordinates: Coordinates,
+    /// Pressure plates the player knows about and that are still armed.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub traps: Vec<TrapView>,
 }
 
 #[derive(Clone, Debug, Default)]
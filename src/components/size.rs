@@ -33,4 +33,15 @@ impl Size {
     pub fn is_average(&self) -> bool {
         matches!(*self, Size::Medium | Size::Average)
     }
+
+    pub fn carry_capacity_modifier(&self) -> i32 {
+        match *self {
+            Size::Tiny => -20,
+            Size::Short | Size::Small => -10,
+            Size::Average | Size::Long | Size::Medium | Size::Narrow | Size::Squat => 0,
+            Size::Large | Size::Tall | Size::Wide => 10,
+            Size::Huge => 20,
+            Size::Massive => 30,
+        }
+    }
 }
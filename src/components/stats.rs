@@ -6,7 +6,7 @@ use rand::rngs::ThreadRng;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
 
-use super::{Attack, Defense, Health, Size};
+use super::{Attack, Attributes, Defense, Health, Size};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -16,6 +16,8 @@ pub struct Stats {
     pub height: Size,
     pub base_attack: Option<Attack>,
     pub base_damage_resistance: Option<Defense>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub attributes: Attributes,
 }
 
 impl Stats {
@@ -42,4 +44,6 @@ pub struct StatsView {
     pub health: Option<Health>,
     pub health_known: bool,
     pub height: Size,
+    pub attributes: Option<Attributes>,
+    pub attributes_known: bool,
 }
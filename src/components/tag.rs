@@ -34,6 +34,7 @@ pub enum Tag {
     Equippable,
     Fixture,
     Instrument,
+    Key,
     Leather,
     Metal,
     Paper,
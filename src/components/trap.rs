@@ -0,0 +1,56 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use uuid::Uuid;
+
+use super::Poison;
+
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum TrapType {
+    /// Set in the floor of a room, and goes off when the player crosses it to leave.
+    PressurePlate,
+    /// Hidden in the lid of a chest or coffin.
+    Needle,
+    /// Strung across an exit.
+    Tripwire,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct Trap {
+    pub id: Uuid,
+    pub trap_type: TrapType,
+    /// Traps stop being armed once they have gone off or been disarmed.
+    pub armed: bool,
+    /// Number of d6 rolled for the damage done when the trap goes off.
+    pub damage_rolls: usize,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub poison: Option<Poison>,
+    /// Total a disarming roll needs to reach.
+    pub difficulty: i32,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Trap"))]
+pub struct TrapView {
+    pub id: String,
+    pub trap_type: TrapType,
+}
@@ -1,10 +1,13 @@
+use std::collections::{HashMap, VecDeque};
+
 #[cfg(feature = "bevy_components")]
 use bevy_ecs::prelude::Component;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
-use crate::components::rooms::Room;
+use crate::components::rooms::{Coordinates, Direction, ExitType, Room};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -12,9 +15,138 @@ use crate::components::rooms::Room;
 pub struct World {
     pub rooms: Vec<Room>,
     pub exit_graph: Vec<ExitMap>,
+    /// Deepest floor any room has been generated on so far.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub deepest_depth: u32,
 }
 
 impl World {
+    pub fn rooms_on_floor(&self, depth: u32) -> Vec<&Room> {
+        self.rooms
+            .iter()
+            .filter(|room| room.depth == depth)
+            .collect()
+    }
+
+    /// A cell is taken when a room is there, or when an exit nobody has gone
+    /// through yet is going to lead there.
+    pub fn is_cell_taken(&self, coordinates: &Coordinates, depth: u32) -> bool {
+        self.rooms.iter().any(|room| {
+            (room.depth == depth && room.coordinates.eq(coordinates))
+                || room.exits.iter().any(|exit| {
+                    let direction = match &exit.direction {
+                        Some(it) => it,
+                        None => return false,
+                    };
+                    let unexplored = self.exit_graph.iter().any(|exit_map| {
+                        exit_map.exit_id.eq(&exit.id) && exit_map.other_room_id(room.id).is_none()
+                    });
+
+                    unexplored
+                        && neighbour_depth(room.depth, direction) == Some(depth)
+                        && room.coordinates.neighbour(direction).eq(coordinates)
+                })
+        })
+    }
+
+    /// Closest free cell on the floor, trying the cells next to the given one
+    /// first and moving further out from there.
+    pub fn nearest_free_cell(&self, coordinates: &Coordinates, depth: u32) -> Coordinates {
+        let mut seen: Vec<Coordinates> = vec![*coordinates];
+        let mut queue: VecDeque<Coordinates> = VecDeque::from([*coordinates]);
+
+        while let Some(current) = queue.pop_front() {
+            for neighbour in Direction::flat()
+                .iter()
+                .map(|direction| current.neighbour(direction))
+            {
+                if seen.contains(&neighbour) {
+                    continue;
+                }
+
+                if !self.is_cell_taken(&neighbour, depth) {
+                    return neighbour;
+                }
+
+                seen.push(neighbour);
+                queue.push_back(neighbour);
+            }
+        }
+
+        // Only so many cells can be taken, so a free one is always found first.
+        *coordinates
+    }
+
+    /// Directions a room placed on the cell could not put a new exit in.
+    pub fn blocked_directions(&self, coordinates: &Coordinates, depth: u32) -> Vec<Direction> {
+        Direction::iter()
+            .filter(|direction| match neighbour_depth(depth, direction) {
+                Some(other_depth) => {
+                    self.is_cell_taken(&coordinates.neighbour(direction), other_depth)
+                }
+                None => true,
+            })
+            .collect()
+    }
+
+    /// Exits to go through, in order, to get from one room to another along the
+    /// fewest rooms. Only rooms `can_enter` allows are passed through, and exits
+    /// that can't be used from the side they're on are skipped.
+    pub fn find_path<F>(&self, from: Uuid, to: Uuid, can_enter: F) -> Option<Vec<Uuid>>
+    where
+        F: Fn(&Uuid) -> bool,
+    {
+        let mut came_from: HashMap<Uuid, (Uuid, Uuid)> = HashMap::new();
+        let mut queue: VecDeque<Uuid> = VecDeque::from([from]);
+
+        while let Some(room_id) = queue.pop_front() {
+            if room_id == to {
+                let mut path: Vec<Uuid> = Vec::new();
+                let mut current = to;
+                while let Some((previous, exit_id)) = came_from.get(&current) {
+                    path.push(*exit_id);
+                    current = *previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            let room = match self.rooms.iter().find(|room| room.id == room_id) {
+                Some(it) => it,
+                None => continue,
+            };
+            for exit in room
+                .exits
+                .iter()
+                .filter(|exit| exit.exit_type.can_be_used() && !exit.is_locked())
+            {
+                let next_id = match self
+                    .exit_graph
+                    .iter()
+                    .find(|exit_map| exit_map.exit_id.eq(&exit.id))
+                    .and_then(|exit_map| exit_map.other_room_id(room_id))
+                {
+                    Some(it) => it,
+                    None => continue,
+                };
+
+                if next_id != from && !came_from.contains_key(&next_id) && can_enter(&next_id) {
+                    came_from.insert(next_id, (room_id, exit.id));
+                    queue.push_back(next_id);
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn has_way_out(&self) -> bool {
+        self.rooms
+            .iter()
+            .flat_map(|room| room.exits.iter())
+            .any(|exit| exit.exit_type == ExitType::WayOut)
+    }
+
     pub fn add_room(&mut self, entrance_id: Uuid, room: Room) {
         if let Some(exit_map) = self
             .exit_graph
@@ -32,6 +164,7 @@ impl World {
                     right_room_id: None,
                 })
                 .for_each(|exit_map| self.exit_graph.push(exit_map));
+            self.deepest_depth = self.deepest_depth.max(room.depth);
             self.rooms.push(room);
         }
     }
@@ -46,6 +179,14 @@ pub struct ExitMap {
     pub right_room_id: Option<Uuid>,
 }
 
+fn neighbour_depth(depth: u32, direction: &Direction) -> Option<u32> {
+    match *direction {
+        Direction::Up => depth.checked_sub(1),
+        Direction::Down => Some(depth + 1),
+        _ => Some(depth),
+    }
+}
+
 impl ExitMap {
     pub fn other_room_id(&self, room_id: Uuid) -> Option<Uuid> {
         if self.left_room_id.eq(&Some(room_id)) {
@@ -63,3 +204,43 @@ impl ExitMap {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::rooms::{Coordinates, Direction},
+        generators::{generator::Generator, RoomGeneratorBuilder},
+    };
+
+    use super::World;
+
+    #[test]
+    fn the_nearest_free_cell_moves_past_taken_neighbours() {
+        let centre = Coordinates { x: 0, y: 0 };
+        let rooms = std::iter::once(centre)
+            .chain(
+                Direction::flat()
+                    .iter()
+                    .map(|direction| centre.neighbour(direction)),
+            )
+            .map(|coordinates| {
+                let mut room = RoomGeneratorBuilder::new()
+                    .coordinates(coordinates)
+                    .build()
+                    .generate();
+                room.exits.clear();
+                room
+            })
+            .collect();
+        let world = World {
+            rooms,
+            exit_graph: Vec::new(),
+            deepest_depth: 0,
+        };
+
+        let free = world.nearest_free_cell(&centre, 0);
+
+        assert!(!world.is_cell_taken(&free, 0));
+        assert_eq!(2, free.x.abs() + free.y.abs());
+    }
+}
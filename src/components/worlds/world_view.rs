@@ -14,6 +14,8 @@ use crate::components::rooms::RoomView;
 pub struct WorldView {
     pub rooms: Vec<RoomView>,
     pub exit_graph: Vec<ExitMapView>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub deepest_depth: u32,
 }
 
 #[derive(Clone, Debug)]
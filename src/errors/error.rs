@@ -1,19 +1,36 @@
 #[derive(Clone, Debug, thiserror::Error, strum_macros::Display)]
 pub enum Error {
     ExitNotFoundError(String),
+    ExitIsOneWay(String),
+    ExitIsLocked(String),
     FixtureNotFoundError(String),
     FixtureCannotBeFound(String),
     FixtureCannotBeOpened(String),
     FixtureHasNoHiddenCompartment(String),
     FixtureHasHiddenCompartmentUnknown(String),
+    FixtureCannotRepairItems(String),
+    FixtureIsLocked(String),
+    GameIsOverError,
+    GraveyardError(String),
     InvalidIdError(String),
     ItemNotDirectlyUsableError(String),
     ItemNotFoundError(String),
     ItemNotThrowableError(String),
     NpcNotFoundError(String),
+    NoPathToRoom(String),
+    NotEnoughGold(u32),
     PlayerIsDeadError,
+    RoomNotFoundError(String),
     SpellNotFoundError(String),
     TooManyWeaponsEquippedError,
     TooManyWearablesEquippedError,
+    TooHeavy(String),
+    TrapNotFound(String),
     ItemCannotBeTakenFromFixture(String),
+    ItemCannotBeRepaired(String),
+    ItemIsBound(String),
+    KeyNotFound(String),
+    NotLocked(String),
+    InvalidContentPack(String),
+    InvalidAchievements(String),
 }
@@ -5,10 +5,11 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::components::{
+    achievements::{PlayerProfile, UnlockedAchievement},
     games::GameState,
     items::{CharacterItem, LocationTag},
     spells::LearnedSpell,
-    PlayerCharacter, Poison,
+    Ghost, PlayerCharacter, Poison,
 };
 
 use super::NpcDamagedByPoison;
@@ -21,21 +22,38 @@ use super::NpcDamagedByPoison;
     serde(rename_all = "snake_case", tag = "event_type")
 )]
 pub enum Event {
+    AchievementUnlocked(super::AchievementUnlocked),
     GameDangerLevelIncreased(u32),
+    GameEnded(super::GameEnded),
     GhostEscapesToTheVoid(super::GhostEscapesToTheVoid),
+    BossEncountered(super::BossEncountered),
     DeadNpcBeaten(super::DeadNpcBeaten),
+    EscortDamaged(super::EscortDamaged),
+    ExitUnlocked(super::ExitUnlocked),
     FixtureHasHiddenCompartmentDiscovered(super::FixtureHasHiddenCompartmentDiscovered),
     FixtureHiddenCompartmentOpened(super::FixtureHiddenCompartmentOpened),
     FixtureOpened(super::FixtureOpened),
+    FixtureUnlocked(super::FixtureUnlocked),
     FixtureViewed(super::FixtureViewed),
+    ItemAffixesDiscovered(super::ItemAffixesDiscovered),
+    ItemAttackDiscovered(super::ItemAttackDiscovered),
+    ItemConsumableDiscovered(super::ItemConsumableDiscovered),
+    ItemDefenseDiscovered(super::ItemDefenseDiscovered),
     ItemTakenFromFixture(super::ItemTakenFromFixture),
     ItemTakenFromNpc(super::ItemTakenFromNpc),
+    LockPickFailed(super::LockPickFailed),
+    NpcAttributesDiscovered(super::NpcAttributesDiscovered),
     NpcCoveredInOil(Uuid),
     NpcDamagedByPoison(NpcDamagedByPoison),
+    NpcHealed(super::NpcHealed),
     NpcHealthDiscovered(super::NpcHealthDiscovered),
     NpcHitWithAcid(Uuid),
+    NpcItemBroken(super::NpcItemBroken),
     NpcItemDestroyed(super::NpcItemDestroyed),
+    NpcItemWornDown(super::NpcItemWornDown),
     NpcMissed(super::NpcMissed),
+    NpcMissedInTheDark(super::NpcMissedInTheDark),
+    NpcOilWashedAway(Uuid),
     NpcPackedDiscovered(super::NpcPackedDiscovered),
     NpcPoisonDurationChanged(super::NpcPoisonEffectDurationChanged),
     NpcPoisonEffectDissipated(super::NpcPoisonEffectDissipated),
@@ -43,9 +61,13 @@ pub enum Event {
     NpcPoisoned(super::NpcPoisoned),
     NpcViewed(super::NpcViewed),
     NpcWeaponReadied(super::NpcWeaponReadied),
+    PlayerDamagedByCold(i32),
     PlayerDamagedByPoison(i32),
     PlayerDropsAllItems,
+    PlayerEscaped(super::PlayerEscaped),
+    PlayerFell(super::PlayerFell),
     PlayerGainedGold(u32),
+    PlayerGainedItem(super::PlayerGainedItem),
     PlayerGainsResurrectionAura,
     PlayerGainsRetributionAura(super::PlayerGainsRetributionAura),
     PlayerGainsShieldAura(super::PlayerGainsShieldAura),
@@ -55,9 +77,12 @@ pub enum Event {
     PlayerHitNpc(super::PlayerHitNpc),
     PlayerItemMoved(super::PlayerItemMoved),
     PlayerHitWithAcid,
+    PlayerItemBroken(Uuid),
     PlayerItemDestroyed(Uuid),
     PlayerItemRemoved(super::PlayerItemRemoved),
+    PlayerItemRepaired(super::PlayerItemRepaired),
     PlayerItemUsed(super::PlayerItemUsed),
+    PlayerItemWornDown(super::PlayerItemWornDown),
     PlayerKilled(super::PlayerKilled),
     PlayerKilledNpc(super::PlayerKilledNpc),
     PlayerMaxHealthChanged(i32),
@@ -74,9 +99,35 @@ pub enum Event {
     PlayerSpellForgotten(super::PlayerSpellForgotten),
     PlayerSpellLearned(super::PlayerSpellLearned),
     PlayerSpellUsed(super::PlayerSpellUsed),
+    QuestCompleted(super::QuestCompleted),
+    QuestFailed(super::QuestFailed),
+    QuestProgressed(super::QuestProgressed),
+    QuestStarted(super::QuestStarted),
     RoomExited(super::RoomExited),
     RoomGenerated(super::RoomGenerated),
     RoomFirstSeen(super::RoomFirstSeen),
+    TrapDisarmed(super::TrapDisarmed),
+    TrapDiscovered(super::TrapDiscovered),
+    TrapTriggered(super::TrapTriggered),
+    TurnTaken,
+}
+
+/// Applies the events that outlast a single run to the player's profile.
+pub fn apply_profile_events(events: &[Event], profile: &PlayerProfile) -> PlayerProfile {
+    let mut new_profile = profile.clone();
+
+    for event in events.iter() {
+        if let Event::AchievementUnlocked(unlocked) = event {
+            if !new_profile.has_unlocked(&unlocked.achievement_id) {
+                new_profile.unlocked_achievements.push(UnlockedAchievement {
+                    achievement_id: unlocked.achievement_id.clone(),
+                    unlocked_at: unlocked.unlocked_at,
+                });
+            }
+        }
+    }
+
+    new_profile
 }
 
 pub fn apply_events(
@@ -89,8 +140,18 @@ pub fn apply_events(
 
     for event in events.iter() {
         match event {
+            Event::TurnTaken => new_game.player_stats_mut(&player.id).turns_taken += 1,
+            Event::GameEnded(game_ended) => {
+                new_game.outcome = Some(game_ended.outcome.clone());
+            }
+            Event::GhostEscapesToTheVoid(ghost_escapes) => new_game.ghosts.push(Ghost {
+                character: ghost_escapes.character.clone(),
+                name: ghost_escapes.name.clone(),
+                killer_name: ghost_escapes.killer_name.clone(),
+            }),
             Event::RoomExited(room_exited) => {
                 new_game.current_room_id = room_exited.new_room_id;
+                new_game.damage_taken_in_room = 0;
             }
             Event::RoomGenerated(room_generated) => new_game
                 .world
@@ -103,23 +164,35 @@ pub fn apply_events(
             }
             Event::PlayerKilledNpc(npc_killed) => {
                 let room = new_game.current_room_mut();
+                let mut species = None;
                 if let Some(position) = room.find_npc_mut(&npc_killed.npc_id) {
                     position.npc.character.kill();
                     position.position_descriptor = None;
+                    species = Some(position.npc.character.species);
                 }
                 new_game.add_player_kill_to_stats(&npc_killed.killer_id);
+                if let Some(species) = species {
+                    *new_game
+                        .player_stats_mut(&npc_killed.killer_id)
+                        .kills_by_species
+                        .entry(species)
+                        .or_default() += 1;
+                }
             }
             Event::PlayerHit(player_hit) => {
                 new_player.character.damage(player_hit.damage);
                 new_game.add_player_damage_taken_to_stats(&player.id, player_hit.damage);
             }
             Event::PlayerKilled(_) => new_player.character.kill(),
-            Event::ItemTakenFromNpc(item_taken_from_npc) => take_item_from_npc(
-                &mut new_game,
-                &mut new_player,
-                &item_taken_from_npc.npc_id,
-                &item_taken_from_npc.item_id,
-            ),
+            Event::ItemTakenFromNpc(item_taken_from_npc) => {
+                take_item_from_npc(
+                    &mut new_game,
+                    &mut new_player,
+                    &item_taken_from_npc.npc_id,
+                    &item_taken_from_npc.item_id,
+                );
+                new_game.player_stats_mut(&player.id).items_looted += 1;
+            }
             Event::NpcWeaponReadied(weapon_readied) => ready_npc_weapon(
                 &mut new_game,
                 &weapon_readied.npc_id,
@@ -139,6 +212,31 @@ pub fn apply_events(
                 knowledge.knows_health = true;
                 new_game.set_npc_knowledge(health_discovered.npc_id, knowledge);
             }
+            Event::NpcAttributesDiscovered(attributes_discovered) => {
+                let mut knowledge = new_game.npc_knowledge(&attributes_discovered.npc_id);
+                knowledge.knows_attributes = true;
+                new_game.set_npc_knowledge(attributes_discovered.npc_id, knowledge);
+            }
+            Event::ItemAttackDiscovered(attack_discovered) => {
+                let mut knowledge = new_game.item_knowledge(&attack_discovered.item_id);
+                knowledge.knows_attack = true;
+                new_game.set_item_knowledge(attack_discovered.item_id, knowledge);
+            }
+            Event::ItemDefenseDiscovered(defense_discovered) => {
+                let mut knowledge = new_game.item_knowledge(&defense_discovered.item_id);
+                knowledge.knows_defense = true;
+                new_game.set_item_knowledge(defense_discovered.item_id, knowledge);
+            }
+            Event::ItemAffixesDiscovered(affixes_discovered) => {
+                let mut knowledge = new_game.item_knowledge(&affixes_discovered.item_id);
+                knowledge.knows_affixes = true;
+                new_game.set_item_knowledge(affixes_discovered.item_id, knowledge);
+            }
+            Event::ItemConsumableDiscovered(consumable_discovered) => {
+                let mut knowledge = new_game.item_knowledge(&consumable_discovered.item_id);
+                knowledge.knows_consumable = true;
+                new_game.set_item_knowledge(consumable_discovered.item_id, knowledge);
+            }
             Event::NpcPackedDiscovered(packed_discovered) => {
                 let mut knowledge = new_game.npc_knowledge(&packed_discovered.npc_id);
                 knowledge.knows_packed_in_inventory = true;
@@ -148,16 +246,29 @@ pub fn apply_events(
                 let mut knowledge = new_game.fixture_knowledge(&has_hidden.fixture_id);
                 knowledge.knows_has_hidden_compartment = true;
                 new_game.set_fixture_knowledge(has_hidden.fixture_id, knowledge);
+                new_game
+                    .player_stats_mut(&player.id)
+                    .hidden_compartments_found += 1;
             }
             Event::RoomFirstSeen(first_seen) => {
                 new_game.rooms_seen.push(first_seen.room_id);
+                new_game.player_stats_mut(&player.id).rooms_explored += 1;
+            }
+            Event::ItemTakenFromFixture(item_taken_from_fixture) => {
+                take_item_from_fixture(
+                    &mut new_game,
+                    &mut new_player,
+                    &item_taken_from_fixture.fixture_id,
+                    &item_taken_from_fixture.item_id,
+                );
+                new_game.player_stats_mut(&player.id).items_looted += 1;
+            }
+            Event::NpcHealed(npc_healed) => {
+                if let Some(position) = new_game.current_room_mut().find_npc_mut(&npc_healed.npc_id)
+                {
+                    position.npc.character.heal(npc_healed.damage_healed);
+                }
             }
-            Event::ItemTakenFromFixture(item_taken_from_fixture) => take_item_from_fixture(
-                &mut new_game,
-                &mut new_player,
-                &item_taken_from_fixture.fixture_id,
-                &item_taken_from_fixture.item_id,
-            ),
             Event::PlayerHealed(player_healed) => {
                 new_player.character.heal(player_healed.damage_healed)
             }
@@ -191,6 +302,11 @@ pub fn apply_events(
                     .find_spell_mut(&player_spell_used.spell_id)
                 {
                     learned_spell.spell.uses -= 1;
+                    *new_game
+                        .player_stats_mut(&player.id)
+                        .spells_cast
+                        .entry(learned_spell.spell.name)
+                        .or_default() += 1;
                 }
             }
             Event::PlayerItemRemoved(player_item_removed) => {
@@ -201,6 +317,30 @@ pub fn apply_events(
             Event::PlayerItemDestroyed(item_id) => {
                 new_player.character.remove_item(item_id);
             }
+            Event::PlayerItemWornDown(player_item_worn_down) => {
+                if let Some(character_item) = new_player
+                    .character
+                    .find_item_mut(&player_item_worn_down.item_id)
+                {
+                    character_item.item.wear(player_item_worn_down.amount);
+                }
+            }
+            Event::PlayerItemBroken(item_id) => {
+                if let Some(character_item) = new_player.character.find_item_mut(item_id) {
+                    character_item.item.break_item();
+                }
+            }
+            Event::PlayerItemRepaired(player_item_repaired) => {
+                if let Some(character_item) = new_player
+                    .character
+                    .find_item_mut(&player_item_repaired.item_id)
+                {
+                    character_item.item.repair();
+                }
+                new_player.gold = new_player
+                    .gold
+                    .saturating_sub(player_item_repaired.gold_spent);
+            }
             Event::PlayerSpellLearned(player_spell_learned) => {
                 new_player.character.spell_memory.add_spell(LearnedSpell {
                     id: player_spell_learned.spell_id,
@@ -209,6 +349,11 @@ pub fn apply_events(
                 });
             }
             Event::PlayerItemUsed(player_item_used) => {
+                // Using something is the surest way to find out what it does.
+                let mut knowledge = new_game.item_knowledge(&player_item_used.item_id);
+                knowledge.knows_consumable = true;
+                new_game.set_item_knowledge(player_item_used.item_id, knowledge);
+
                 let mut character_item =
                     match new_player.character.remove_item(&player_item_used.item_id) {
                         Some(it) => it,
@@ -218,6 +363,53 @@ pub fn apply_events(
                 new_player.character.add_item(character_item);
             }
             Event::FixtureOpened(opened) => open_fixture(&mut new_game, &opened.fixture_id),
+            Event::ExitUnlocked(unlocked) => {
+                // Both rooms on either side of the exit have their own copy of it.
+                for exit in new_game
+                    .world
+                    .rooms
+                    .iter_mut()
+                    .flat_map(|room| room.exits.iter_mut())
+                    .filter(|exit| exit.id.eq(&unlocked.exit_id))
+                {
+                    if let Some(lock) = exit.lock.as_mut() {
+                        lock.locked = false;
+                    }
+                }
+            }
+            Event::TrapDiscovered(discovered) => {
+                match new_game
+                    .current_room()
+                    .find_fixture_with_trap(&discovered.trap_id)
+                    .map(|position| position.fixture.id)
+                {
+                    Some(fixture_id) => {
+                        let mut knowledge = new_game.fixture_knowledge(&fixture_id);
+                        knowledge.knows_has_trap = true;
+                        new_game.set_fixture_knowledge(fixture_id, knowledge);
+                    }
+                    None => {
+                        if !new_game.known_trap_ids.contains(&discovered.trap_id) {
+                            new_game.known_trap_ids.push(discovered.trap_id);
+                        }
+                    }
+                }
+            }
+            Event::TrapDisarmed(disarmed) => disarm_trap(&mut new_game, &disarmed.trap_id),
+            Event::TrapTriggered(triggered) => {
+                new_player.character.damage(triggered.damage);
+                new_game.add_player_damage_taken_to_stats(&player.id, triggered.damage);
+                disarm_trap(&mut new_game, &triggered.trap_id);
+            }
+            Event::FixtureUnlocked(unlocked) => {
+                if let Some(lock) = new_game
+                    .current_room_mut()
+                    .find_fixture_mut(&unlocked.fixture_id)
+                    .and_then(|position| position.fixture.lock.as_mut())
+                {
+                    lock.locked = false;
+                }
+            }
             Event::FixtureHiddenCompartmentOpened(opened) => {
                 open_fixture_hidden_compartment(&mut new_game, &opened.fixture_id)
             }
@@ -232,9 +424,19 @@ pub fn apply_events(
                 {
                     position.npc.character.damage(poison_damage.damage);
                 }
+                new_game.player_stats_mut(&player.id).poison_damage_done += poison_damage.damage;
+            }
+            Event::PlayerDamagedByCold(damage) => {
+                new_player.character.damage(*damage);
+                new_game.add_player_damage_taken_to_stats(&player.id, *damage);
             }
             Event::PlayerDamagedByPoison(damage) => {
                 new_player.character.damage(*damage);
+                new_game.add_player_damage_taken_to_stats(&player.id, *damage);
+            }
+            Event::PlayerFell(player_fell) => {
+                new_player.character.damage(player_fell.damage);
+                new_game.add_player_damage_taken_to_stats(&player.id, player_fell.damage);
             }
             Event::PlayerPoisonDurationChanged(change) => {
                 if let Some(poison) = new_player.character.current_effects.poison.as_mut() {
@@ -282,6 +484,40 @@ pub fn apply_events(
             }
             Event::PlayerGainedGold(gold) => {
                 new_player.gold += gold;
+                new_game.player_stats_mut(&player.id).gold_earned += gold;
+            }
+            Event::PlayerGainedItem(player_gained_item) => {
+                new_player.character.add_item(CharacterItem {
+                    item: player_gained_item.item.clone(),
+                    equipped_location: LocationTag::Packed,
+                    at_the_ready: false,
+                });
+            }
+            Event::QuestStarted(quest_started) => {
+                new_game.quests.push(quest_started.quest.clone());
+            }
+            Event::QuestProgressed(quest_progressed) => {
+                if let Some(quest) = new_game.find_quest_mut(&quest_progressed.quest_id) {
+                    quest.progress = quest_progressed.progress;
+                }
+            }
+            Event::QuestCompleted(quest_completed) => {
+                if let Some(quest) = new_game.find_quest_mut(&quest_completed.quest_id) {
+                    quest.completed = true;
+                }
+            }
+            Event::QuestFailed(quest_failed) => {
+                if let Some(quest) = new_game.find_quest_mut(&quest_failed.quest_id) {
+                    quest.failed = true;
+                }
+            }
+            Event::EscortDamaged(escort_damaged) => {
+                if let Some(npc) = new_game
+                    .find_quest_mut(&escort_damaged.quest_id)
+                    .and_then(|quest| quest.escort_mut())
+                {
+                    npc.character.damage(escort_damaged.damage);
+                }
             }
             Event::PlayerShieldAuraDamaged(damage) => {
                 if let Some(shield_aura) = new_player.character.current_effects.shield_aura.as_mut()
@@ -314,11 +550,44 @@ pub fn apply_events(
                         .remove_item(&npc_item_destroyed.item_id);
                 }
             }
+            Event::NpcItemWornDown(npc_item_worn_down) => {
+                if let Some(character_item) = new_game
+                    .current_room_mut()
+                    .find_npc_mut(&npc_item_worn_down.npc_id)
+                    .and_then(|position| {
+                        position
+                            .npc
+                            .character
+                            .find_item_mut(&npc_item_worn_down.item_id)
+                    })
+                {
+                    character_item.item.wear(npc_item_worn_down.amount);
+                }
+            }
+            Event::NpcItemBroken(npc_item_broken) => {
+                if let Some(character_item) = new_game
+                    .current_room_mut()
+                    .find_npc_mut(&npc_item_broken.npc_id)
+                    .and_then(|position| {
+                        position
+                            .npc
+                            .character
+                            .find_item_mut(&npc_item_broken.item_id)
+                    })
+                {
+                    character_item.item.break_item();
+                }
+            }
             Event::NpcCoveredInOil(npc_id) => {
                 if let Some(position) = new_game.current_room_mut().find_npc_mut(npc_id) {
                     position.npc.character.current_effects.covered_in_oil = true;
                 }
             }
+            Event::NpcOilWashedAway(npc_id) => {
+                if let Some(position) = new_game.current_room_mut().find_npc_mut(npc_id) {
+                    position.npc.character.current_effects.covered_in_oil = false;
+                }
+            }
             Event::PlayerDropsAllItems => {
                 new_game
                     .current_room_mut()
@@ -330,6 +599,7 @@ pub fn apply_events(
                     .current_room_mut()
                     .remove_loose_item(item_id)
                     .unwrap();
+                new_game.player_stats_mut(&player.id).items_looted += 1;
                 let packed_item = CharacterItem {
                     equipped_location: LocationTag::Packed,
                     item,
@@ -338,19 +608,32 @@ pub fn apply_events(
                 new_player.character.add_item(packed_item)
             }
             Event::NpcMissed(_)
+            | Event::NpcMissedInTheDark(_)
             | Event::DeadNpcBeaten(_)
             | Event::PlayerMissed(_)
             | Event::NpcViewed(_)
             | Event::FixtureViewed(_)
+            | Event::LockPickFailed(_)
             | Event::NpcHitWithAcid(_)
             | Event::PlayerHitWithAcid
-            | Event::GhostEscapesToTheVoid(_) => {}
+            | Event::BossEncountered(_)
+            | Event::PlayerEscaped(_)
+            | Event::AchievementUnlocked(_) => {}
         }
     }
 
     (new_game, new_player)
 }
 
+/// Exits are copied into the rooms on both sides, so every copy of the trap is disarmed.
+fn disarm_trap(new_game: &mut GameState, trap_id: &Uuid) {
+    for room in new_game.world.rooms.iter_mut() {
+        if let Some(trap) = room.find_trap_mut(trap_id) {
+            trap.armed = false;
+        }
+    }
+}
+
 fn open_fixture(new_game: &mut GameState, fixture_id: &Uuid) {
     let fixture_position = new_game
         .current_room_mut()
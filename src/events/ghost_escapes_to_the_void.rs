@@ -11,4 +11,6 @@ use crate::components::Character;
 pub struct GhostEscapesToTheVoid {
     pub character: Character,
     pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub killer_name: Option<String>,
 }
@@ -1,16 +1,32 @@
+mod achievement_unlocked;
+mod boss_encountered;
 mod dead_npc_beaten;
+mod escort_damaged;
 mod event;
+mod exit_unlocked;
 mod fixture_has_hidden_compartment_discovered;
 mod fixture_hidden_compartment_opened;
 mod fixture_opened;
+mod fixture_unlocked;
 mod fixture_viewed;
+mod game_ended;
 mod ghost_escapes_to_the_void;
+mod item_affixes_discovered;
+mod item_attack_discovered;
+mod item_consumable_discovered;
+mod item_defense_discovered;
 mod item_taken_from_fixture;
 mod item_taken_from_npc;
+mod lock_pick_failed;
+mod npc_attributes_discovered;
 mod npc_damaged_by_poison;
+mod npc_healed;
 mod npc_health_discovered;
+mod npc_item_broken;
 mod npc_item_destroyed;
+mod npc_item_worn_down;
 mod npc_missed;
+mod npc_missed_in_the_dark;
 mod npc_packed_discovered;
 mod npc_poison_effect_dissipated;
 mod npc_poison_effect_duration_changed;
@@ -18,6 +34,9 @@ mod npc_poison_level_changed;
 mod npc_poisoned;
 mod npc_viewed;
 mod npc_weapon_readied;
+mod player_escaped;
+mod player_fell;
+mod player_gained_item;
 mod player_gains_retribution_aura;
 mod player_gains_shield_aura;
 mod player_healed;
@@ -25,7 +44,9 @@ mod player_hit;
 mod player_hit_npc;
 mod player_item_moved;
 mod player_item_removed;
+mod player_item_repaired;
 mod player_item_used;
+mod player_item_worn_down;
 mod player_killed;
 mod player_killed_npc;
 mod player_missed;
@@ -33,30 +54,50 @@ mod player_poisoned;
 mod player_spell_forgotten;
 mod player_spell_learned;
 mod player_spell_used;
+mod quest_completed;
+mod quest_failed;
+mod quest_progressed;
+mod quest_started;
 mod room_exited;
 mod room_first_seen;
 mod room_generated;
+mod trap_disarmed;
+mod trap_discovered;
+mod trap_triggered;
 
 pub use {
-    dead_npc_beaten::DeadNpcBeaten, event::apply_events, event::Event,
+    achievement_unlocked::AchievementUnlocked, boss_encountered::BossEncountered,
+    dead_npc_beaten::DeadNpcBeaten, escort_damaged::EscortDamaged, event::apply_events,
+    event::apply_profile_events, event::Event, exit_unlocked::ExitUnlocked,
     fixture_has_hidden_compartment_discovered::FixtureHasHiddenCompartmentDiscovered,
     fixture_hidden_compartment_opened::FixtureHiddenCompartmentOpened,
-    fixture_opened::FixtureOpened, fixture_viewed::FixtureViewed,
+    fixture_opened::FixtureOpened, fixture_unlocked::FixtureUnlocked,
+    fixture_viewed::FixtureViewed, game_ended::GameEnded,
     ghost_escapes_to_the_void::GhostEscapesToTheVoid,
-    item_taken_from_fixture::ItemTakenFromFixture, item_taken_from_npc::ItemTakenFromNpc,
-    npc_damaged_by_poison::NpcDamagedByPoison, npc_health_discovered::NpcHealthDiscovered,
-    npc_item_destroyed::NpcItemDestroyed, npc_missed::NpcMissed,
-    npc_packed_discovered::NpcPackedDiscovered,
+    item_affixes_discovered::ItemAffixesDiscovered, item_attack_discovered::ItemAttackDiscovered,
+    item_consumable_discovered::ItemConsumableDiscovered,
+    item_defense_discovered::ItemDefenseDiscovered, item_taken_from_fixture::ItemTakenFromFixture,
+    item_taken_from_npc::ItemTakenFromNpc, lock_pick_failed::LockPickFailed,
+    npc_attributes_discovered::NpcAttributesDiscovered, npc_damaged_by_poison::NpcDamagedByPoison,
+    npc_healed::NpcHealed, npc_health_discovered::NpcHealthDiscovered,
+    npc_item_broken::NpcItemBroken, npc_item_destroyed::NpcItemDestroyed,
+    npc_item_worn_down::NpcItemWornDown, npc_missed::NpcMissed,
+    npc_missed_in_the_dark::NpcMissedInTheDark, npc_packed_discovered::NpcPackedDiscovered,
     npc_poison_effect_dissipated::NpcPoisonEffectDissipated,
     npc_poison_effect_duration_changed::NpcPoisonEffectDurationChanged,
     npc_poison_level_changed::NpcPoisonLevelChanged, npc_poisoned::NpcPoisoned,
-    npc_viewed::NpcViewed, npc_weapon_readied::NpcWeaponReadied,
+    npc_viewed::NpcViewed, npc_weapon_readied::NpcWeaponReadied, player_escaped::PlayerEscaped,
+    player_fell::PlayerFell, player_gained_item::PlayerGainedItem,
     player_gains_retribution_aura::PlayerGainsRetributionAura,
     player_gains_shield_aura::PlayerGainsShieldAura, player_healed::PlayerHealed,
     player_hit::PlayerHit, player_hit_npc::PlayerHitNpc, player_item_moved::PlayerItemMoved,
-    player_item_removed::PlayerItemRemoved, player_item_used::PlayerItemUsed,
+    player_item_removed::PlayerItemRemoved, player_item_repaired::PlayerItemRepaired,
+    player_item_used::PlayerItemUsed, player_item_worn_down::PlayerItemWornDown,
     player_killed::PlayerKilled, player_killed_npc::PlayerKilledNpc, player_missed::PlayerMissed,
     player_poisoned::PlayerPoisoned, player_spell_forgotten::PlayerSpellForgotten,
     player_spell_learned::PlayerSpellLearned, player_spell_used::PlayerSpellUsed,
-    room_exited::RoomExited, room_first_seen::RoomFirstSeen, room_generated::RoomGenerated,
+    quest_completed::QuestCompleted, quest_failed::QuestFailed, quest_progressed::QuestProgressed,
+    quest_started::QuestStarted, room_exited::RoomExited, room_first_seen::RoomFirstSeen,
+    room_generated::RoomGenerated, trap_disarmed::TrapDisarmed, trap_discovered::TrapDiscovered,
+    trap_triggered::TrapTriggered,
 };
@@ -1,19 +1,22 @@
 use crate::{
     actions::{
-        Action, AttackNpc, CastSpellOnNpc, CastSpellOnPlayer, ExitRoom, InspectFixture, InspectNpc,
-        LookAtFixture, LookAtNpc, LootFixture, LootNpc, MovePlayerItem, OpenFixture,
-        OpenFixtureHiddenCompartment, UseItemOnPlayer,
+        Action, AttackNpc, CastSpellOnNpc, CastSpellOnPlayer, DisarmTrap, ExitRoom, InspectFixture,
+        InspectItem, InspectNpc, LookAtFixture, LookAtNpc, LootFixture, LootNpc, MovePlayerItem,
+        OpenFixture, OpenFixtureHiddenCompartment, RepairItem, SearchRoom, TravelToRoom,
+        UnlockExit, UnlockFixture, UseItemOnPlayer,
     },
     components::{
+        achievements::{Achievement, PlayerProfile},
         games::GameState,
         items::{
             ConsumableEffectName, {packed_tags_for_item_type, ready_tag_for_item_type},
         },
-        PlayerCharacter,
+        Lock, PlayerCharacter,
     },
     errors::Error,
-    events::Event,
+    events::{apply_profile_events, Event},
     handlers::{handle_action, HandledAction},
+    systems::achievements,
 };
 
 pub struct Game {
@@ -34,6 +37,28 @@ impl Game {
         Ok(events)
     }
 
+    /// Handles the action, then unlocks on the profile any of the achievements
+    /// earned by it. The unlocks are returned after the events of the action.
+    pub fn handle_action_for_profile(
+        &mut self,
+        action: &Action,
+        achievements: &[Achievement],
+        profile: &mut PlayerProfile,
+    ) -> Result<Vec<Event>, Error> {
+        let previous = Game {
+            state: self.state.clone(),
+            player: self.player.clone(),
+        };
+        let mut events = self.handle_action(action)?;
+
+        let mut unlocked =
+            achievements::check(achievements, profile, action, &events, &previous, self);
+        *profile = apply_profile_events(&unlocked, profile);
+        events.append(&mut unlocked);
+
+        Ok(events)
+    }
+
     pub fn current_actions(&self) -> Vec<Action> {
         let fixture_actions = self
             .state
@@ -49,10 +74,18 @@ impl Game {
                     Action::InspectFixture(InspectFixture {
                         fixture_id: fixture.id.to_string(),
                         discover_hidden_compartment: true,
+                        discover_trap: true,
                     }),
                 ];
 
-                if fixture.can_be_opened && !fixture.open {
+                if fixture.is_locked() {
+                    for pick_lock in self.unlock_options(&fixture.lock) {
+                        actions.push(Action::UnlockFixture(UnlockFixture {
+                            fixture_id: fixture.id.to_string(),
+                            pick_lock,
+                        }));
+                    }
+                } else if fixture.can_be_opened && !fixture.open {
                     actions.push(Action::OpenFixture(OpenFixture {
                         fixture_id: fixture.id.to_string(),
                     }));
@@ -105,6 +138,21 @@ impl Game {
                     }))
                 }
 
+                if fixture.fixture_type.can_repair_items() {
+                    for character_item in self.player.character.inventory.equipment.iter() {
+                        if let Some(durability) = &character_item.item.durability {
+                            if durability.needs_repair()
+                                && self.player.gold >= durability.repair_cost()
+                            {
+                                actions.push(Action::RepairItem(RepairItem {
+                                    item_id: character_item.item.id.to_string(),
+                                    fixture_id: fixture.id.to_string(),
+                                }));
+                            }
+                        }
+                    }
+                }
+
                 actions
             });
 
@@ -123,6 +171,7 @@ impl Game {
                         npc_id: npc.id.to_string(),
                         discover_health: true,
                         discover_packed_items: true,
+                        discover_attributes: true,
                     }),
                 ];
 
@@ -155,11 +204,66 @@ impl Game {
                 actions
             });
 
-        let exit_actions = self.state.current_room_exits().into_iter().map(|id| {
-            Action::ExitRoom(ExitRoom {
-                exit_id: id.to_string(),
+        let exit_actions = self
+            .state
+            .current_room()
+            .exits
+            .iter()
+            .filter(|exit| exit.exit_type.can_be_used())
+            .flat_map(|exit| {
+                if exit.is_locked() {
+                    self.unlock_options(&exit.lock)
+                        .into_iter()
+                        .map(|pick_lock| {
+                            Action::UnlockExit(UnlockExit {
+                                exit_id: exit.id.to_string(),
+                                pick_lock,
+                            })
+                        })
+                        .collect()
+                } else {
+                    vec![Action::ExitRoom(ExitRoom {
+                        exit_id: exit.id.to_string(),
+                    })]
+                }
+            });
+
+        let travel_actions = self
+            .state
+            .rooms_seen
+            .iter()
+            .filter(|room_id| room_id.ne(&&self.state.current_room_id))
+            .filter(|room_id| {
+                self.state
+                    .world
+                    .find_path(self.state.current_room_id, **room_id, |id| {
+                        self.state.rooms_seen.contains(id)
+                    })
+                    .is_some()
             })
-        });
+            .map(|room_id| {
+                Action::TravelToRoom(TravelToRoom {
+                    room_id: room_id.to_string(),
+                })
+            });
+
+        let room = self.state.current_room();
+        let trap_actions = std::iter::once(Action::SearchRoom(SearchRoom {})).chain(
+            room.traps
+                .iter()
+                .chain(room.exits.iter().filter_map(|exit| exit.trap.as_ref()))
+                .chain(
+                    room.fixture_positions
+                        .iter()
+                        .filter_map(|position| position.fixture.trap.as_ref()),
+                )
+                .filter(|trap| trap.armed && self.state.knows_trap(&trap.id))
+                .map(|trap| {
+                    Action::DisarmTrap(DisarmTrap {
+                        trap_id: trap.id.to_string(),
+                    })
+                }),
+        );
 
         let spell_actions = self
             .player
@@ -182,7 +286,33 @@ impl Game {
                 .flat_map(|character_item| {
                     let mut actions: Vec<Action> = Vec::new();
 
+                    if character_item.item.is_equippable()
+                        && !self
+                            .state
+                            .item_knowledge(&character_item.item.id)
+                            .knows_affixes
+                    {
+                        actions.push(Action::InspectItem(InspectItem {
+                            item_id: character_item.item.id.to_string(),
+                            discover_attack: false,
+                            discover_defense: false,
+                            discover_consumable: false,
+                            discover_affixes: true,
+                        }));
+                    }
+
                     if character_item.is_consumable() {
+                        let knowledge = self.state.item_knowledge(&character_item.item.id);
+                        if !knowledge.knows_consumable {
+                            actions.push(Action::InspectItem(InspectItem {
+                                item_id: character_item.item.id.to_string(),
+                                discover_attack: false,
+                                discover_defense: false,
+                                discover_consumable: true,
+                                discover_affixes: false,
+                            }));
+                        }
+
                         match &character_item.item.consumable {
                             Some(consumable) => match &consumable.effect.name {
                                 ConsumableEffectName::LearnSpell
@@ -218,9 +348,25 @@ impl Game {
 
         npc_actions
             .chain(exit_actions)
+            .chain(travel_actions)
             .chain(fixture_actions)
+            .chain(trap_actions)
             .chain(spell_actions)
             .chain(item_actions)
             .collect()
     }
+
+    /// Whether a lock can be opened with a key the player has, and picked.
+    fn unlock_options(&self, lock: &Option<Lock>) -> Vec<bool> {
+        let has_key = lock
+            .as_ref()
+            .and_then(|lock| lock.key_id)
+            .is_some_and(|key_id| self.player.character.inventory.find_item(&key_id).is_some());
+
+        if has_key {
+            vec![false, true]
+        } else {
+            vec![true]
+        }
+    }
 }
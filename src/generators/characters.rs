@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use rand::Rng;
 use strum::IntoEnumIterator;
 
@@ -6,7 +8,7 @@ use crate::components::{
 };
 
 use super::{
-    generator::Generator, stats::build_default_health_rolls_for_danger_level,
+    generator::Generator, stats::build_default_health_rolls_for_danger_level, ContentPack,
     InventoryGeneratorBuilder,
 };
 
@@ -16,6 +18,7 @@ struct CharacterPrototype {
     pub life_modifier: Option<LifeModifier>,
     pub has_inventory: bool,
     pub danger_level: u32,
+    pub content_pack: Arc<ContentPack>,
 }
 
 #[derive(Default, Clone)]
@@ -25,6 +28,7 @@ pub struct CharacterGeneratorBuilder {
     life_modifier: Option<LifeModifier>,
     has_inventory: Option<bool>,
     danger_level: Option<u32>,
+    content_pack: Option<Arc<ContentPack>>,
 }
 
 impl CharacterGeneratorBuilder {
@@ -62,14 +66,22 @@ impl CharacterGeneratorBuilder {
         self
     }
 
+    pub fn content_pack(&mut self, content_pack: Arc<ContentPack>) -> &mut Self {
+        self.content_pack = Some(content_pack);
+
+        self
+    }
+
     pub fn build(&self) -> impl Generator<Character> {
         let mut rng = rand::thread_rng();
         let danger_level = self.danger_level.unwrap_or(1);
+        let content_pack = self.content_pack.clone().unwrap_or_default();
 
         let inventory_gen_builder = match &self.inventory_generator_builder {
             Some(builder) => builder.to_owned(),
             None => InventoryGeneratorBuilder::new()
                 .danger_level(danger_level)
+                .content_pack(content_pack.clone())
                 .to_owned(),
         };
 
@@ -88,6 +100,7 @@ impl CharacterGeneratorBuilder {
             life_modifier: self.life_modifier,
             has_inventory: self.has_inventory.unwrap_or(true),
             danger_level,
+            content_pack,
         }
     }
 }
@@ -100,8 +113,12 @@ impl Generator<Character> for CharacterPrototype {
             Inventory::default()
         };
 
-        let stats_generator =
-            build_default_health_rolls_for_danger_level(&self.species, self.danger_level, true);
+        let stats_generator = build_default_health_rolls_for_danger_level(
+            &self.species,
+            self.danger_level,
+            true,
+            &self.content_pack,
+        );
         let stats = stats_generator.generate();
 
         Character {
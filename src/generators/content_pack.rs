@@ -0,0 +1,335 @@
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::components::{
+    fixtures::FixtureType,
+    items::{Descriptor, ItemType},
+    spells::SpellName,
+    Material, Species, Tag, Tagged,
+};
+#[cfg(feature = "json")]
+use crate::errors::Error;
+
+use super::utils::{item_descriptors, materials};
+
+const DEFAULT_HEALTH_ROLLS: usize = 3;
+
+/// The content the generators read from. A game carries its own pack, so
+/// games with different packs can run side by side. Anything a pack leaves out,
+/// whether a whole section or a single species, item type or spell, comes from
+/// the built-in content.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(default)
+)]
+pub struct ContentPack {
+    pub species: Vec<SpeciesContent>,
+    pub item_materials: Vec<ItemMaterials>,
+    pub fixture_tags: Vec<FixtureTags>,
+    pub spell_uses: Vec<SpellUses>,
+    pub descriptors: Vec<DescriptorContent>,
+    pub name_words: Vec<SpeciesNameWords>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct SpeciesContent {
+    pub species: Species,
+    /// Number of d6 rolled for the max health of a level 1 member of the species.
+    pub health_rolls: usize,
+    pub min_group_size: usize,
+    pub max_group_size: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct ItemMaterials {
+    pub item_type: ItemType,
+    pub materials: Vec<Material>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct FixtureTags {
+    pub fixture_type: FixtureType,
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct SpellUses {
+    pub spell_name: SpellName,
+    pub min_uses: i32,
+    pub max_uses: i32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct DescriptorContent {
+    pub descriptor: Descriptor,
+    /// Lowest item level the descriptor can show up on, with no lower bound when empty.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub min_level: Option<u32>,
+    /// Highest item level the descriptor can show up on, with no upper bound when empty.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_level: Option<u32>,
+    /// The descriptor can be given to anything with one of these tags.
+    pub tags: Vec<Tag>,
+}
+
+/// Example names for a species, used to train the Markov model its NPC names
+/// are generated from.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct SpeciesNameWords {
+    pub species: Species,
+    pub words: Vec<String>,
+}
+
+impl Default for ContentPack {
+    fn default() -> Self {
+        Self {
+            species: Species::iter()
+                .map(|species| {
+                    let group_size = default_group_size(&species);
+                    SpeciesContent {
+                        species,
+                        health_rolls: default_health_rolls(&species),
+                        min_group_size: *group_size.start(),
+                        max_group_size: *group_size.end(),
+                    }
+                })
+                .collect(),
+            item_materials: ItemType::iter()
+                .map(|item_type| ItemMaterials {
+                    item_type,
+                    materials: materials::default_materials(&item_type),
+                })
+                .collect(),
+            fixture_tags: FixtureType::iter()
+                .map(|fixture_type| FixtureTags {
+                    fixture_type,
+                    tags: fixture_type.tags(),
+                })
+                .collect(),
+            spell_uses: SpellName::iter()
+                .map(|spell_name| {
+                    let uses = default_spell_uses(&spell_name);
+                    SpellUses {
+                        spell_name,
+                        min_uses: *uses.start(),
+                        max_uses: *uses.end(),
+                    }
+                })
+                .collect(),
+            descriptors: Descriptor::iter()
+                .map(|descriptor| {
+                    let (min_level, max_level) = item_descriptors::default_level_range(&descriptor);
+                    DescriptorContent {
+                        descriptor,
+                        min_level,
+                        max_level,
+                        tags: item_descriptors::default_tags(&descriptor),
+                    }
+                })
+                .collect(),
+            name_words: Vec::new(),
+        }
+    }
+}
+
+impl ContentPack {
+    #[cfg(feature = "json")]
+    pub fn from_json(text: &str) -> Result<Self, Error> {
+        serde_json::from_str(text).map_err(|e| Error::InvalidContentPack(e.to_string()))
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::InvalidContentPack(e.to_string()))
+    }
+
+    pub fn health_rolls(&self, species: &Species) -> usize {
+        self.species
+            .iter()
+            .find(|content| content.species.eq(species))
+            .map(|content| content.health_rolls)
+            .unwrap_or_else(|| default_health_rolls(species))
+    }
+
+    pub fn group_size(&self, species: &Species) -> RangeInclusive<usize> {
+        self.species
+            .iter()
+            .find(|content| content.species.eq(species))
+            .map(|content| {
+                let min = content.min_group_size.max(1);
+                min..=content.max_group_size.max(min)
+            })
+            .unwrap_or_else(|| default_group_size(species))
+    }
+
+    pub fn materials(&self, item_type: &ItemType) -> Vec<Material> {
+        self.item_materials
+            .iter()
+            .find(|content| content.item_type.eq(item_type))
+            .map(|content| content.materials.clone())
+            .unwrap_or_else(|| materials::default_materials(item_type))
+    }
+
+    pub fn fixture_tags(&self, fixture_type: &FixtureType) -> Vec<Tag> {
+        self.fixture_tags
+            .iter()
+            .find(|content| content.fixture_type.eq(fixture_type))
+            .map(|content| content.tags.clone())
+            .unwrap_or_else(|| fixture_type.tags())
+    }
+
+    pub fn spell_uses(&self, spell_name: &SpellName) -> RangeInclusive<i32> {
+        self.spell_uses
+            .iter()
+            .find(|content| content.spell_name.eq(spell_name))
+            .map(|content| {
+                let min = content.min_uses.max(1);
+                min..=content.max_uses.max(min)
+            })
+            .unwrap_or_else(|| default_spell_uses(spell_name))
+    }
+
+    pub fn descriptor_valid_for_level(&self, descriptor: &Descriptor, level: u32) -> bool {
+        self.descriptors
+            .iter()
+            .find(|content| content.descriptor.eq(descriptor))
+            .map(|content| {
+                content.min_level.is_none_or(|min| level >= min)
+                    && content.max_level.is_none_or(|max| level <= max)
+            })
+            .unwrap_or_else(|| {
+                let (min_level, max_level) = item_descriptors::default_level_range(descriptor);
+                min_level.is_none_or(|min| level >= min) && max_level.is_none_or(|max| level <= max)
+            })
+    }
+
+    pub fn name_words(&self, species: &Species) -> Vec<String> {
+        self.name_words
+            .iter()
+            .filter(|content| content.species.eq(species))
+            .flat_map(|content| content.words.clone())
+            .collect()
+    }
+
+    pub fn descriptors_matching_tags(&self, tags: &[Tag]) -> Vec<Descriptor> {
+        Descriptor::iter()
+            .filter(|descriptor| {
+                self.descriptors
+                    .iter()
+                    .find(|content| content.descriptor.eq(descriptor))
+                    .map(|content| content.tags.clone())
+                    .unwrap_or_else(|| item_descriptors::default_tags(descriptor))
+                    .iter()
+                    .any(|tag| tags.contains(tag))
+            })
+            .collect()
+    }
+}
+
+fn default_health_rolls(species: &Species) -> usize {
+    match *species {
+        Species::Ogre => 5,
+        Species::Dragonkin | Species::Phantom | Species::Rockoblin | Species::Shadow => 4,
+        _ => DEFAULT_HEALTH_ROLLS,
+    }
+}
+
+fn default_group_size(species: &Species) -> RangeInclusive<usize> {
+    match *species {
+        Species::Bugbear | Species::Hobgoblin => 1..=2,
+        Species::Goblin | Species::Kobold => 1..=3,
+        _ => 1..=1,
+    }
+}
+
+fn default_spell_uses(spell_name: &SpellName) -> RangeInclusive<i32> {
+    match *spell_name {
+        SpellName::AcidSplash => 1..=3,
+        SpellName::ElectricBlast
+        | SpellName::RagingFireball
+        | SpellName::PoisonCloud
+        | SpellName::PoisonDart
+        | SpellName::TinyShield => 1..=6,
+        SpellName::Heal => 1..=5,
+        SpellName::GreatHeal | SpellName::Phoenix => 1..=1,
+        SpellName::QuickHeal => 3..=10,
+        SpellName::Retribution => 2..=3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "json")]
+    fn a_loaded_pack_changes_what_is_generated() {
+        use std::sync::Arc;
+
+        use crate::{
+            components::{items::ItemType, Material},
+            generators::{generator::Generator, items::item_generator_for_level},
+        };
+
+        use super::ContentPack;
+
+        let pack = ContentPack::from_json(
+            r#"{ "item_materials": [{ "item_type": "long_sword", "materials": ["gold"] }] }"#,
+        )
+        .unwrap();
+
+        let item =
+            item_generator_for_level(&ItemType::LongSword, false, 1, &Arc::new(pack)).generate();
+
+        assert_eq!(Some(Material::Gold), item.material);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn anything_left_out_of_a_pack_comes_from_the_built_in_content() {
+        use crate::components::{items::ItemType, Species};
+
+        use super::ContentPack;
+
+        let pack = ContentPack::from_json(
+            r#"{ "name_words": [{ "species": "goblin", "words": ["snag", "grik"] }] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(5, pack.health_rolls(&Species::Ogre));
+        assert_eq!(
+            ContentPack::default().materials(&ItemType::LongSword),
+            pack.materials(&ItemType::LongSword)
+        );
+        assert_eq!(2, pack.name_words(&Species::Goblin).len());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn descriptors_left_out_of_a_pack_keep_their_built_in_tags() {
+        use crate::components::{items::Descriptor, Tag};
+
+        use super::ContentPack;
+
+        let pack = ContentPack::from_json(
+            r#"{ "descriptors": [{ "descriptor": "rusty", "tags": ["bone"] }] }"#,
+        )
+        .unwrap();
+
+        let bone_descriptors = pack.descriptors_matching_tags(&[Tag::Bone]);
+        assert!(bone_descriptors.contains(&Descriptor::Rusty));
+        assert!(bone_descriptors.contains(&Descriptor::Bleached));
+        let metal_descriptors = pack.descriptors_matching_tags(&[Tag::Metal]);
+        assert!(!metal_descriptors.contains(&Descriptor::Rusty));
+        assert!(metal_descriptors.contains(&Descriptor::Shiny));
+    }
+}
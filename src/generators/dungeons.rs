@@ -0,0 +1,540 @@
+use std::{ops::RangeInclusive, sync::Arc};
+
+use rand::{prelude::ThreadRng, Rng};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        games::Ruleset,
+        rooms::{Coordinates, Direction, ExitType, Room, RoomType},
+        worlds::{ExitMap, World},
+    },
+    utils::rolls::roll_percent_succeeds,
+};
+
+use super::{
+    generator::Generator, ContentPack, ExitGenerationArgs, RoomGeneratorBuilder,
+    RoomNpcGenerationArgs,
+};
+
+const MIN_ROOMS: usize = 2;
+const MAX_EXITS_PER_ROOM: usize = 4;
+
+/// A whole floor generated up front, with every exit already leading somewhere.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct Dungeon {
+    pub world: World,
+    pub entry_room_id: Uuid,
+    pub goal_room_id: Uuid,
+    /// Rooms on the guaranteed route from the entry to the goal, in order.
+    pub critical_path: Vec<Uuid>,
+}
+
+#[derive(Default, Clone)]
+pub struct DungeonGeneratorBuilder {
+    num_rooms: Option<RangeInclusive<usize>>,
+    critical_path_length: Option<usize>,
+    loop_chance: Option<i32>,
+    allow_dead_ends: Option<bool>,
+    danger_level: Option<u32>,
+    danger_per_room: Option<u32>,
+    goal_room_type: Option<RoomType>,
+    possible_room_types: Option<Vec<RoomType>>,
+    depth: Option<u32>,
+    ruleset: Option<Ruleset>,
+    content_pack: Option<Arc<ContentPack>>,
+}
+
+impl DungeonGeneratorBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn num_rooms(&mut self, num_rooms: RangeInclusive<usize>) -> &mut Self {
+        self.num_rooms = Some(num_rooms);
+
+        self
+    }
+
+    /// Number of rooms from the entry to the goal, both included.
+    pub fn critical_path_length(&mut self, critical_path_length: usize) -> &mut Self {
+        self.critical_path_length = Some(critical_path_length);
+
+        self
+    }
+
+    /// Percent chance for each side room to get an extra exit back into the rest of the floor.
+    pub fn loop_chance(&mut self, loop_chance: i32) -> &mut Self {
+        self.loop_chance = Some(loop_chance);
+
+        self
+    }
+
+    pub fn allow_dead_ends(&mut self, allow_dead_ends: bool) -> &mut Self {
+        self.allow_dead_ends = Some(allow_dead_ends);
+
+        self
+    }
+
+    /// Danger level of the entry room.
+    pub fn danger_level(&mut self, danger_level: u32) -> &mut Self {
+        self.danger_level = Some(danger_level);
+
+        self
+    }
+
+    /// How much the danger level rises for each room between a room and the entry.
+    pub fn danger_per_room(&mut self, danger_per_room: u32) -> &mut Self {
+        self.danger_per_room = Some(danger_per_room);
+
+        self
+    }
+
+    pub fn goal_room_type(&mut self, goal_room_type: RoomType) -> &mut Self {
+        self.goal_room_type = Some(goal_room_type);
+
+        self
+    }
+
+    pub fn possible_room_types(&mut self, possible_room_types: Vec<RoomType>) -> &mut Self {
+        self.possible_room_types = Some(possible_room_types);
+
+        self
+    }
+
+    /// Floor the dungeon is generated on.
+    pub fn depth(&mut self, depth: u32) -> &mut Self {
+        self.depth = Some(depth);
+
+        self
+    }
+
+    /// Ruleset the NPCs in every room are generated with.
+    pub fn ruleset(&mut self, ruleset: Ruleset) -> &mut Self {
+        self.ruleset = Some(ruleset);
+
+        self
+    }
+
+    pub fn content_pack(&mut self, content_pack: Arc<ContentPack>) -> &mut Self {
+        self.content_pack = Some(content_pack);
+
+        self
+    }
+
+    pub fn build(&self) -> impl Generator<Dungeon> {
+        let possible_room_types = match &self.possible_room_types {
+            Some(it) if !it.is_empty() => it.clone(),
+            _ => RoomType::iter()
+                .filter(|room_type| room_type.ne(&RoomType::EntryWay))
+                .collect(),
+        };
+
+        DungeonPrototype {
+            num_rooms: self.num_rooms.clone().unwrap_or(8..=12),
+            critical_path_length: self.critical_path_length,
+            loop_chance: self.loop_chance.unwrap_or(20),
+            allow_dead_ends: self.allow_dead_ends.unwrap_or(true),
+            danger_level: self.danger_level.unwrap_or(1),
+            danger_per_room: self.danger_per_room.unwrap_or(1),
+            goal_room_type: self.goal_room_type.unwrap_or(RoomType::TempleHall),
+            possible_room_types,
+            depth: self.depth.unwrap_or(0),
+            ruleset: self.ruleset.clone().unwrap_or_default(),
+            content_pack: self.content_pack.clone().unwrap_or_default(),
+        }
+    }
+}
+
+struct DungeonPrototype {
+    num_rooms: RangeInclusive<usize>,
+    critical_path_length: Option<usize>,
+    loop_chance: i32,
+    allow_dead_ends: bool,
+    danger_level: u32,
+    danger_per_room: u32,
+    goal_room_type: RoomType,
+    possible_room_types: Vec<RoomType>,
+    depth: u32,
+    ruleset: Ruleset,
+    content_pack: Arc<ContentPack>,
+}
+
+struct RoomPlan {
+    room_type: RoomType,
+    distance: u32,
+    coordinates: Coordinates,
+    exit_ids: Vec<Uuid>,
+}
+
+struct Connection {
+    exit_id: Uuid,
+    left: usize,
+    right: usize,
+    /// Wall of the left room the exit is in.
+    direction: Direction,
+}
+
+impl Generator<Dungeon> for DungeonPrototype {
+    fn generate(&self) -> Dungeon {
+        let mut rng = rand::thread_rng();
+        let (mut plans, connections, goal_index) = self.layout(&mut rng);
+        // The goal leads further down, to a floor that is generated once it is reached.
+        let stairs_down_id = Uuid::new_v4();
+        plans[goal_index].exit_ids.push(stairs_down_id);
+
+        let mut rooms: Vec<Room> = plans
+            .iter()
+            .map(|plan| {
+                let mut room = RoomGeneratorBuilder::new()
+                    .room_type(plan.room_type)
+                    .depth(self.depth)
+                    .coordinates(plan.coordinates)
+                    .danger_level(self.danger_level + plan.distance * self.danger_per_room)
+                    .exit_generation_args(ExitGenerationArgs {
+                        num_exits: Some(plan.exit_ids.len() as u16..=plan.exit_ids.len() as u16),
+                        possible_exit_types: Some(floor_exit_types()),
+                    })
+                    .room_npc_generation_args(RoomNpcGenerationArgs {
+                        ruleset: Some(self.ruleset.clone()),
+                        ..RoomNpcGenerationArgs::default()
+                    })
+                    .content_pack(self.content_pack.clone())
+                    .build()
+                    .generate();
+
+                room.exits
+                    .iter_mut()
+                    .zip(plan.exit_ids.iter())
+                    .for_each(|(exit, exit_id)| exit.id = *exit_id);
+                room
+            })
+            .collect();
+
+        // Both sides of an exit should look like the same doorway, in facing walls.
+        // The left room is the one nearer the entry, so its lock is the one kept and
+        // the key for any lock on the far side is thrown away with it.
+        for connection in &connections {
+            let exit = rooms[connection.left]
+                .exits
+                .iter_mut()
+                .find(|exit| exit.id.eq(&connection.exit_id))
+                .map(|exit| {
+                    exit.direction = Some(connection.direction);
+                    exit.clone()
+                });
+            if let Some(exit) = exit {
+                if let Some(other) = rooms[connection.right]
+                    .exits
+                    .iter_mut()
+                    .find(|other| other.id.eq(&connection.exit_id))
+                {
+                    let discarded_key_id = other.lock.as_ref().and_then(|lock| lock.key_id);
+                    *other = exit;
+                    other.direction = Some(connection.direction.opposite());
+                    if let Some(key_id) = discarded_key_id {
+                        discard_key(&mut rooms[connection.right], &key_id);
+                    }
+                }
+            }
+        }
+
+        if let Some(stairs_down) = rooms[goal_index]
+            .exits
+            .iter_mut()
+            .find(|exit| exit.id.eq(&stairs_down_id))
+        {
+            stairs_down.exit_type = ExitType::StaircaseDown;
+            stairs_down.direction = Some(Direction::Down);
+            stairs_down.material = None;
+            stairs_down.descriptors = Vec::new();
+        }
+
+        let exit_graph: Vec<ExitMap> = connections
+            .iter()
+            .map(|connection| ExitMap {
+                exit_id: connection.exit_id,
+                left_room_id: Some(rooms[connection.left].id),
+                right_room_id: Some(rooms[connection.right].id),
+            })
+            .chain(std::iter::once(ExitMap {
+                exit_id: stairs_down_id,
+                left_room_id: Some(rooms[goal_index].id),
+                right_room_id: None,
+            }))
+            .collect();
+
+        let critical_path = (0..=goal_index).map(|index| rooms[index].id).collect();
+
+        Dungeon {
+            entry_room_id: rooms[0].id,
+            goal_room_id: rooms[goal_index].id,
+            critical_path,
+            world: World {
+                rooms,
+                exit_graph,
+                deepest_depth: self.depth,
+            },
+        }
+    }
+}
+
+impl DungeonPrototype {
+    /// Decides where every room sits on the grid and how they connect, before any room
+    /// is generated. The critical path takes the first indexes, from the entry at 0 to the goal.
+    fn layout(&self, rng: &mut ThreadRng) -> (Vec<RoomPlan>, Vec<Connection>, usize) {
+        let num_rooms = rng.gen_range(self.num_rooms.clone()).max(MIN_ROOMS);
+        let path_length = self
+            .critical_path_length
+            .unwrap_or((num_rooms / 2).max(MIN_ROOMS))
+            .clamp(MIN_ROOMS, num_rooms);
+        let goal_index = path_length - 1;
+
+        let path = critical_path_walk(rng, path_length);
+        let mut plans: Vec<RoomPlan> = path
+            .into_iter()
+            .enumerate()
+            .map(|(index, coordinates)| RoomPlan {
+                room_type: if index == 0 {
+                    RoomType::EntryWay
+                } else if index == goal_index {
+                    self.goal_room_type
+                } else {
+                    self.random_room_type(rng)
+                },
+                distance: index as u32,
+                coordinates,
+                exit_ids: Vec::new(),
+            })
+            .collect();
+        let mut connections: Vec<Connection> = Vec::new();
+
+        for index in 1..path_length {
+            connect(&mut plans, &mut connections, index - 1, index);
+        }
+
+        // Side rooms hang off anything but the goal, so the goal stays at the end of the path.
+        while plans.len() < num_rooms {
+            let candidates: Vec<(usize, Direction)> = plans
+                .iter()
+                .enumerate()
+                .filter(|(index, plan)| {
+                    *index != goal_index && plan.exit_ids.len() < MAX_EXITS_PER_ROOM
+                })
+                .flat_map(|(index, plan)| {
+                    Direction::flat()
+                        .into_iter()
+                        .filter(|direction| is_free(&plans, &plan.coordinates.neighbour(direction)))
+                        .map(move |direction| (index, direction))
+                })
+                .collect();
+            if candidates.is_empty() {
+                break;
+            }
+
+            let (parent, direction) = candidates[rng.gen_range(0..candidates.len())];
+            let room_type = self.random_room_type(rng);
+            plans.push(RoomPlan {
+                room_type,
+                distance: plans[parent].distance + 1,
+                coordinates: plans[parent].coordinates.neighbour(&direction),
+                exit_ids: Vec::new(),
+            });
+            let index = plans.len() - 1;
+            connect(&mut plans, &mut connections, parent, index);
+        }
+
+        // Loops can only join rooms that sit next to each other.
+        for index in path_length..plans.len() {
+            let is_dead_end = plans[index].exit_ids.len() == 1;
+            let wants_loop = roll_percent_succeeds(rng, self.loop_chance)
+                || (is_dead_end && !self.allow_dead_ends);
+            if !wants_loop {
+                continue;
+            }
+
+            let candidates: Vec<usize> = (1..plans.len())
+                .filter(|other| *other != index && *other != goal_index)
+                .filter(|other| plans[*other].exit_ids.len() < MAX_EXITS_PER_ROOM)
+                .filter(|other| direction_between(&plans[index], &plans[*other]).is_some())
+                .filter(|other| !connected(&connections, index, *other))
+                .collect();
+            if candidates.is_empty() {
+                continue;
+            }
+            let other = candidates[rng.gen_range(0..candidates.len())];
+            connect(&mut plans, &mut connections, index, other);
+        }
+
+        (plans, connections, goal_index)
+    }
+
+    fn random_room_type(&self, rng: &mut ThreadRng) -> RoomType {
+        let index = rng.gen_range(0..self.possible_room_types.len());
+        self.possible_room_types
+            .get(index)
+            .cloned()
+            .unwrap_or(RoomType::Room)
+    }
+}
+
+/// A walk across the grid that never steps on itself, starting again if it walks
+/// itself into a corner.
+fn critical_path_walk(rng: &mut ThreadRng, length: usize) -> Vec<Coordinates> {
+    let mut path = vec![Coordinates::default()];
+    while path.len() < length {
+        let last = path[path.len() - 1];
+        let free: Vec<Coordinates> = Direction::flat()
+            .iter()
+            .map(|direction| last.neighbour(direction))
+            .filter(|coordinates| !path.contains(coordinates))
+            .collect();
+
+        if free.is_empty() {
+            path = vec![Coordinates::default()];
+        } else {
+            path.push(free[rng.gen_range(0..free.len())]);
+        }
+    }
+
+    path
+}
+
+fn discard_key(room: &mut Room, key_id: &Uuid) {
+    for position in room.npc_positions.iter_mut() {
+        position.npc.character.remove_item(key_id);
+    }
+    for position in room.fixture_positions.iter_mut() {
+        position.fixture.remove_item(key_id);
+    }
+}
+
+fn is_free(plans: &[RoomPlan], coordinates: &Coordinates) -> bool {
+    !plans.iter().any(|plan| plan.coordinates.eq(coordinates))
+}
+
+fn direction_between(from: &RoomPlan, to: &RoomPlan) -> Option<Direction> {
+    Direction::flat()
+        .into_iter()
+        .find(|direction| from.coordinates.neighbour(direction).eq(&to.coordinates))
+}
+
+fn connect(plans: &mut [RoomPlan], connections: &mut Vec<Connection>, left: usize, right: usize) {
+    let direction = match direction_between(&plans[left], &plans[right]) {
+        Some(it) => it,
+        None => return,
+    };
+    let exit_id = Uuid::new_v4();
+    plans[left].exit_ids.push(exit_id);
+    plans[right].exit_ids.push(exit_id);
+    connections.push(Connection {
+        exit_id,
+        left,
+        right,
+        direction,
+    });
+}
+
+fn connected(connections: &[Connection], first: usize, second: usize) -> bool {
+    connections.iter().any(|connection| {
+        (connection.left == first && connection.right == second)
+            || (connection.left == second && connection.right == first)
+    })
+}
+
+fn floor_exit_types() -> Vec<ExitType> {
+    vec![
+        ExitType::Door,
+        ExitType::DugOutTunnelEntrance,
+        ExitType::Hallway,
+        ExitType::HoleInTheWall,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{components::rooms::RoomType, generators::generator::Generator};
+
+    use super::DungeonGeneratorBuilder;
+
+    #[test]
+    fn every_exit_leads_to_a_room() {
+        let dungeon = DungeonGeneratorBuilder::new()
+            .num_rooms(10..=10)
+            .critical_path_length(5)
+            .allow_dead_ends(false)
+            .build()
+            .generate();
+
+        assert_eq!(10, dungeon.world.rooms.len());
+        assert_eq!(5, dungeon.critical_path.len());
+        assert_eq!(RoomType::EntryWay, dungeon.world.rooms[0].room_type);
+        assert_eq!(
+            1,
+            dungeon
+                .world
+                .exit_graph
+                .iter()
+                .filter(|exit_map| exit_map.right_room_id.is_none())
+                .count()
+        );
+        for room in &dungeon.world.rooms {
+            for exit in &room.exits {
+                assert!(dungeon
+                    .world
+                    .exit_graph
+                    .iter()
+                    .any(|exit_map| exit_map.exit_id.eq(&exit.id)));
+            }
+        }
+    }
+
+    #[test]
+    fn goal_can_be_reached_from_the_entry() {
+        let mut dungeon = DungeonGeneratorBuilder::new()
+            .num_rooms(12..=12)
+            .critical_path_length(6)
+            .build()
+            .generate();
+        // Locked exits can't be travelled through until they're unlocked.
+        for exit in dungeon
+            .world
+            .rooms
+            .iter_mut()
+            .flat_map(|room| room.exits.iter_mut())
+        {
+            exit.lock = None;
+        }
+
+        let path = dungeon
+            .world
+            .find_path(dungeon.entry_room_id, dungeon.goal_room_id, |_| true)
+            .unwrap();
+        assert!(!path.is_empty());
+        assert!(path.len() < dungeon.critical_path.len());
+        assert!(dungeon
+            .world
+            .find_path(dungeon.entry_room_id, dungeon.goal_room_id, |id| {
+                id.ne(&dungeon.goal_room_id)
+            })
+            .is_none());
+    }
+
+    #[test]
+    fn rooms_do_not_share_a_cell() {
+        let dungeon = DungeonGeneratorBuilder::new()
+            .num_rooms(20..=20)
+            .build()
+            .generate();
+
+        for (index, room) in dungeon.world.rooms.iter().enumerate() {
+            assert!(!dungeon.world.rooms[index + 1..]
+                .iter()
+                .any(|other| other.coordinates.eq(&room.coordinates)));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use rand::{prelude::ThreadRng, Rng};
 use strum::IntoEnumIterator;
@@ -15,6 +15,7 @@ use crate::{
 
 use super::{
     generator::Generator, items::item_generator_for_level, utils::item_descriptors::matches_tags,
+    ContentPack,
 };
 
 const HAS_MATERIAL_CHANCE: i32 = 90;
@@ -26,6 +27,7 @@ pub struct FixturePrototype {
     pub num_hidden_items: RangeInclusive<usize>,
     pub has_hidden_compartment: bool,
     pub danger_level: u32,
+    pub content_pack: Arc<ContentPack>,
 }
 
 pub fn get_generator(
@@ -38,6 +40,7 @@ pub fn get_generator(
         num_items: 0..=2,
         num_hidden_items: 0..=2,
         danger_level: 1,
+        content_pack: Arc::default(),
     }
 }
 
@@ -45,6 +48,7 @@ pub fn get_generator_for_level(
     fixture_type: &FixtureType,
     has_hidden_compartment: bool,
     danger_level: u32,
+    content_pack: &Arc<ContentPack>,
 ) -> impl Generator<Fixture> {
     FixturePrototype {
         danger_level,
@@ -52,6 +56,7 @@ pub fn get_generator_for_level(
         has_hidden_compartment,
         num_items: 0..=2,
         num_hidden_items: 0..=2,
+        content_pack: content_pack.clone(),
     }
 }
 
@@ -91,14 +96,17 @@ impl Generator<Fixture> for FixturePrototype {
         let mut possible_descriptors: Vec<Descriptor> = match &material {
             Some(material) => {
                 let tags: Vec<Tag> = self
-                    .fixture_type
-                    .tags()
+                    .content_pack
+                    .fixture_tags(&self.fixture_type)
                     .into_iter()
                     .chain(material.tags().into_iter())
                     .collect();
-                matches_tags(&tags)
+                matches_tags(&tags, &self.content_pack)
             }
-            None => matches_tags(&self.fixture_type.tags()),
+            None => matches_tags(
+                &self.content_pack.fixture_tags(&self.fixture_type),
+                &self.content_pack,
+            ),
         };
         let mut descriptors: Vec<Descriptor> = Vec::new();
         while num_descriptors > 0 {
@@ -121,6 +129,7 @@ impl Generator<Fixture> for FixturePrototype {
                 &size,
                 &mut rng,
                 self.danger_level,
+                &self.content_pack,
             )
             .into_iter()
             .map(|item| FixtureItem {
@@ -141,6 +150,7 @@ impl Generator<Fixture> for FixturePrototype {
                 &size,
                 &mut rng,
                 self.danger_level,
+                &self.content_pack,
             )
             .into_iter()
             .map(|item| FixtureItem {
@@ -168,6 +178,8 @@ impl Generator<Fixture> for FixturePrototype {
             can_be_opened: fixture_can_be_opened(&self.fixture_type),
             open: false,
             hidden_compartment_open: false,
+            lock: None,
+            trap: None,
         }
     }
 }
@@ -213,6 +225,7 @@ fn build_items(
     size: &Size,
     rng: &mut ThreadRng,
     danger_level: u32,
+    content_pack: &Arc<ContentPack>,
 ) -> Vec<Item> {
     if num_items == 0 {
         return Vec::new();
@@ -228,7 +241,8 @@ fn build_items(
                 let item_type_index = rng.gen_range(0..item_types.len());
                 match item_types.get(item_type_index) {
                     Some(item_type) => {
-                        let generator = item_generator_for_level(item_type, false, danger_level);
+                        let generator =
+                            item_generator_for_level(item_type, false, danger_level, content_pack);
                         Some(generator.generate())
                     }
                     None => None,
@@ -436,7 +450,7 @@ fn possible_item_types(fixture_type: &FixtureType, size: &Size) -> Vec<ItemType>
         | (FixtureType::WeaponRack, Size::Tall)
         | (FixtureType::WeaponRack, Size::Tiny)
         | (FixtureType::WeaponRack, Size::Wide) => ItemType::iter()
-            .filter(|item_type| !matches!(item_type, ItemType::Scroll))
+            .filter(|item_type| !matches!(item_type, ItemType::Scroll | ItemType::Key))
             .collect(),
         _ => Vec::new(),
     }
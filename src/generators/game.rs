@@ -1,27 +1,116 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use uuid::Uuid;
 
 use crate::components::{
-    games::GameState,
-    rooms::RoomType,
+    games::{GameState, Ruleset, VictoryConditions},
+    rooms::{Direction, RoomType},
     worlds::{ExitMap, World},
 };
 
-use super::{generator::Generator, RoomGeneratorBuilder};
+use super::{
+    generator::Generator,
+    rooms::{assign_exit_directions, way_out},
+    ContentPack, Dungeon, DungeonGeneratorBuilder, RoomGeneratorBuilder, RoomNpcGenerationArgs,
+};
+
+/// Any ruleset left out is the normal one, without victory conditions the
+/// game can only end in death, and without a content pack the built-in content
+/// is used.
+pub fn game_generator(
+    ruleset: Option<Ruleset>,
+    victory_conditions: Option<VictoryConditions>,
+    content_pack: Option<Arc<ContentPack>>,
+) -> impl Generator<GameState> {
+    GamePrototype {
+        ruleset: ruleset.unwrap_or_default(),
+        victory_conditions: victory_conditions.unwrap_or_default(),
+        content_pack: content_pack.unwrap_or_default(),
+    }
+}
+
+/// Starts the game on a floor that is laid out up front, instead of rooms
+/// being generated as their exits are first used.
+pub fn dungeon_game_generator(
+    dungeon_generator_builder: &DungeonGeneratorBuilder,
+    ruleset: Option<Ruleset>,
+    victory_conditions: Option<VictoryConditions>,
+    content_pack: Option<Arc<ContentPack>>,
+) -> impl Generator<GameState> {
+    DungeonGamePrototype {
+        dungeon_generator_builder: dungeon_generator_builder.clone(),
+        ruleset: ruleset.unwrap_or_default(),
+        victory_conditions: victory_conditions.unwrap_or_default(),
+        content_pack: content_pack.unwrap_or_default(),
+    }
+}
 
-pub fn game_generator() -> impl Generator<GameState> {
-    GamePrototype {}
+struct GamePrototype {
+    ruleset: Ruleset,
+    victory_conditions: VictoryConditions,
+    content_pack: Arc<ContentPack>,
 }
 
-struct GamePrototype {}
+struct DungeonGamePrototype {
+    dungeon_generator_builder: DungeonGeneratorBuilder,
+    ruleset: Ruleset,
+    victory_conditions: VictoryConditions,
+    content_pack: Arc<ContentPack>,
+}
+
+impl Generator<GameState> for DungeonGamePrototype {
+    fn generate(&self) -> GameState {
+        let mut dungeon = self
+            .dungeon_generator_builder
+            .clone()
+            .ruleset(self.ruleset.clone())
+            .content_pack(self.content_pack.clone())
+            .build()
+            .generate();
+        if self
+            .victory_conditions
+            .escape_depth
+            .is_some_and(|escape_depth| dungeon.world.deepest_depth >= escape_depth)
+        {
+            place_way_out(&mut dungeon);
+        }
+
+        GameState {
+            id: Uuid::new_v4(),
+            name: None,
+            current_room_id: dungeon.entry_room_id,
+            rooms_seen: vec![dungeon.entry_room_id],
+            world: dungeon.world,
+            all_knowledge_unlocked: false,
+            player_npc_knowledge: HashMap::new(),
+            player_fixture_knowledge: HashMap::new(),
+            player_item_knowledge: HashMap::new(),
+            player_statistics: HashMap::new(),
+            known_trap_ids: Vec::new(),
+            quests: Vec::new(),
+            ruleset: self.ruleset.clone(),
+            damage_taken_in_room: 0,
+            victory_conditions: self.victory_conditions.clone(),
+            outcome: None,
+            danger_level: 1,
+            ghosts: Vec::new(),
+            content_pack: self.content_pack.clone(),
+        }
+    }
+}
 
 impl Generator<GameState> for GamePrototype {
     fn generate(&self) -> GameState {
-        let entry = RoomGeneratorBuilder::new()
+        let mut entry = RoomGeneratorBuilder::new()
             .room_type(RoomType::EntryWay)
+            .room_npc_generation_args(RoomNpcGenerationArgs {
+                ruleset: Some(self.ruleset.clone()),
+                ..RoomNpcGenerationArgs::default()
+            })
+            .content_pack(self.content_pack.clone())
             .build()
             .generate();
+        assign_exit_directions(&mut entry.exits, None, &[Direction::Up]);
 
         let exit_maps: Vec<ExitMap> = entry
             .exits
@@ -41,13 +130,90 @@ impl Generator<GameState> for GamePrototype {
             world: World {
                 rooms: vec![entry],
                 exit_graph: exit_maps,
+                deepest_depth: 0,
             },
             all_knowledge_unlocked: false,
             player_npc_knowledge: HashMap::new(),
             player_fixture_knowledge: HashMap::new(),
+            player_item_knowledge: HashMap::new(),
             player_statistics: HashMap::new(),
+            known_trap_ids: Vec::new(),
+            quests: Vec::new(),
+            ruleset: self.ruleset.clone(),
+            damage_taken_in_room: 0,
+            victory_conditions: self.victory_conditions.clone(),
+            outcome: None,
             danger_level: 1,
             ghosts: Vec::new(),
+            content_pack: self.content_pack.clone(),
+        }
+    }
+}
+
+/// Puts the way out in the goal room, in a wall with nothing behind it. A goal
+/// room with no wall to spare leaves it to a floor further down.
+fn place_way_out(dungeon: &mut Dungeon) {
+    let goal_room_id = dungeon.goal_room_id;
+    let blocked = match dungeon
+        .world
+        .rooms
+        .iter()
+        .find(|room| room.id.eq(&goal_room_id))
+    {
+        Some(room) => {
+            let mut blocked = dungeon
+                .world
+                .blocked_directions(&room.coordinates, room.depth);
+            blocked.extend(room.exits.iter().filter_map(|exit| exit.direction));
+            blocked
+        }
+        None => return,
+    };
+
+    let mut exits = vec![way_out()];
+    assign_exit_directions(&mut exits, None, &blocked);
+    if let Some(exit) = exits.pop() {
+        dungeon.world.exit_graph.push(ExitMap {
+            exit_id: exit.id,
+            left_room_id: Some(goal_room_id),
+            right_room_id: None,
+        });
+        if let Some(room) = dungeon
+            .world
+            .rooms
+            .iter_mut()
+            .find(|room| room.id.eq(&goal_room_id))
+        {
+            room.exits.push(exit);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::games::VictoryConditions,
+        generators::{generator::Generator, DungeonGeneratorBuilder},
+    };
+
+    use super::dungeon_game_generator;
+
+    #[test]
+    fn a_dungeon_at_the_escape_depth_has_a_way_out() {
+        let victory_conditions = VictoryConditions {
+            escape_depth: Some(0),
+            ..VictoryConditions::default()
+        };
+
+        let state = dungeon_game_generator(
+            DungeonGeneratorBuilder::new().num_rooms(6..=6),
+            None,
+            Some(victory_conditions),
+            None,
+        )
+        .generate();
+
+        assert!(state.world.has_way_out());
+        assert!(state.victory_conditions.escape_depth.is_some());
+    }
+}
@@ -1,5 +1,5 @@
 use rand::{prelude::ThreadRng, Rng};
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 use strum::IntoEnumIterator;
 use uuid::Uuid;
 
@@ -20,6 +20,7 @@ use super::{
     generator::Generator,
     items::item_generator_for_level,
     utils::item_types::{type_is_for_weapon, type_is_for_wearable},
+    ContentPack,
 };
 
 #[derive(Default, Clone)]
@@ -30,6 +31,7 @@ pub struct InventoryGeneratorBuilder {
     danger_level: Option<u32>,
     generate_consumable_chance: Option<i32>,
     generate_throwable_chance: Option<i32>,
+    content_pack: Option<Arc<ContentPack>>,
 }
 
 const GENERATE_CONSUMABLE_CHANCE: i32 = 25;
@@ -104,10 +106,18 @@ impl InventoryGeneratorBuilder {
         self
     }
 
+    pub fn content_pack(&mut self, content_pack: Arc<ContentPack>) -> &mut Self {
+        self.content_pack = Some(content_pack);
+
+        self
+    }
+
     pub fn build(&self) -> impl Generator<Inventory> {
         let item_types = match &self.possible_item_types {
             Some(it) => it.clone(),
-            None => ItemType::iter().collect(),
+            None => ItemType::iter()
+                .filter(|item_type| !matches!(item_type, ItemType::Key))
+                .collect(),
         };
 
         let num_equipped_weapons = match &self.num_equipped_weapons {
@@ -131,10 +141,125 @@ impl InventoryGeneratorBuilder {
             generate_throwable_chance: self
                 .generate_throwable_chance
                 .unwrap_or(GENERATE_POT_CHANCE),
+            content_pack: self.content_pack.clone().unwrap_or_default(),
         }
     }
 }
 
+/// A random spell, stronger the higher the danger level.
+pub fn learn_spell_effect(
+    rng: &mut ThreadRng,
+    danger_level: u32,
+    content_pack: &ContentPack,
+) -> LearnSpellEffect {
+    let spell_names: Vec<SpellName> = SpellName::iter().collect();
+    let index = rng.gen_range(0..spell_names.len());
+    let spell_name = spell_names.get(index).unwrap();
+
+    let spell_uses: i32 = rng.gen_range(content_pack.spell_uses(spell_name));
+
+    let spell_attack = if matches!(
+        spell_name,
+        SpellName::RagingFireball | SpellName::ElectricBlast
+    ) {
+        let num_rolls = if (1..=10).contains(&danger_level) {
+            2
+        } else if (11..=25).contains(&danger_level) {
+            5
+        } else if (26..=40).contains(&danger_level) {
+            9
+        } else if (41..=60).contains(&danger_level) {
+            15
+        } else {
+            20
+        };
+        Some(Attack {
+            num_rolls,
+            modifier: 0,
+            effects: Vec::new(),
+        })
+    } else if spell_name == &SpellName::Retribution {
+        let num_rolls = if (1..=10).contains(&danger_level) {
+            3
+        } else if (11..=25).contains(&danger_level) {
+            4
+        } else if (26..=40).contains(&danger_level) {
+            6
+        } else if (41..=60).contains(&danger_level) {
+            8
+        } else {
+            10
+        };
+
+        Some(Attack {
+            num_rolls,
+            modifier: -1,
+            effects: Vec::new(),
+        })
+    } else if spell_name == &SpellName::QuickHeal {
+        let num_rolls = if (1..=10).contains(&danger_level) {
+            1
+        } else if (11..=25).contains(&danger_level) {
+            2
+        } else if (26..=40).contains(&danger_level) {
+            3
+        } else if (41..=60).contains(&danger_level) {
+            4
+        } else {
+            5
+        };
+        Some(Attack {
+            num_rolls,
+            modifier: 0,
+            effects: Vec::new(),
+        })
+    } else if spell_name == &SpellName::Heal {
+        let num_rolls = if (1..=10).contains(&danger_level) {
+            2
+        } else if (11..=25).contains(&danger_level) {
+            4
+        } else if (26..=40).contains(&danger_level) {
+            6
+        } else if (41..=60).contains(&danger_level) {
+            8
+        } else {
+            10
+        };
+
+        Some(Attack {
+            num_rolls,
+            modifier: 0,
+            effects: Vec::new(),
+        })
+    } else {
+        None
+    };
+
+    let spell_defense = if spell_name == &SpellName::TinyShield {
+        let damage_resistance = if (1..=10).contains(&danger_level) {
+            rng.gen_range(2..=10)
+        } else if (11..=25).contains(&danger_level) {
+            rng.gen_range(5..=20)
+        } else if (26..=40).contains(&danger_level) {
+            rng.gen_range(10..=30)
+        } else if (41..=60).contains(&danger_level) {
+            rng.gen_range(15..=40)
+        } else {
+            rng.gen_range(20..=50)
+        };
+        Some(Defense { damage_resistance })
+    } else {
+        None
+    };
+
+    LearnSpellEffect {
+        spell_name: *spell_name,
+        spell_attack,
+        spell_defense,
+        spell_uses,
+    }
+}
+
 struct InventoryPrototype {
     pub item_types: Vec<ItemType>,
     pub num_equipped_weapons: RangeInclusive<u16>,
@@ -142,6 +267,7 @@ struct InventoryPrototype {
     pub danger_level: u32,
     pub generate_consumable_chance: i32,
     pub generate_throwable_chance: i32,
+    pub content_pack: Arc<ContentPack>,
 }
 
 impl InventoryPrototype {
@@ -186,7 +312,8 @@ impl InventoryPrototype {
                 Some(it) => *it,
                 None => continue,
             };
-            let generator = item_generator_for_level(weapon_type, true, self.danger_level);
+            let generator =
+                item_generator_for_level(weapon_type, true, self.danger_level, &self.content_pack);
             let weapon = generator.generate();
 
             equipped_weapons.push(CharacterItem {
@@ -238,7 +365,12 @@ impl InventoryPrototype {
             };
 
             used_types.push(*wearable_type);
-            let generator = item_generator_for_level(wearable_type, true, self.danger_level);
+            let generator = item_generator_for_level(
+                wearable_type,
+                true,
+                self.danger_level,
+                &self.content_pack,
+            );
             let wearable = generator.generate();
 
             equipped_wearables.push(CharacterItem {
@@ -251,23 +383,8 @@ impl InventoryPrototype {
         equipped_wearables
     }
 
-    fn spell_uses(&self, rng: &mut ThreadRng, spell_name: &SpellName) -> i32 {
-        match *spell_name {
-            SpellName::AcidSplash => rng.gen_range(1..=3),
-            SpellName::ElectricBlast
-            | SpellName::RagingFireball
-            | SpellName::PoisonCloud
-            | SpellName::PoisonDart
-            | SpellName::TinyShield => rng.gen_range(1..=6),
-            SpellName::Heal => rng.gen_range(1..=5),
-            SpellName::GreatHeal | SpellName::Phoenix => 1,
-            SpellName::QuickHeal => rng.gen_range(3..=10),
-            SpellName::Retribution => rng.gen_range(2..=3),
-        }
-    }
-
     fn pots(&self, rng: &mut ThreadRng) -> Vec<CharacterItem> {
-        let possible_materials = super::utils::materials::possible_materials(&ItemType::Pot);
+        let possible_materials = self.content_pack.materials(&ItemType::Pot);
         let material = if possible_materials.is_empty() {
             None
         } else {
@@ -281,6 +398,7 @@ impl InventoryPrototype {
             &ItemType::Pot,
             &material,
             self.danger_level,
+            &self.content_pack,
         );
         let descriptors = if possible_descriptors.is_empty() {
             Vec::new()
@@ -308,6 +426,8 @@ impl InventoryPrototype {
                         oil_splash_effect: Some(OilSplashEffect { covers_all_enemies }),
                     },
                 }),
+                durability: None,
+                affixes: Vec::new(),
             },
             equipped_location: LocationTag::Packed,
             at_the_ready: false,
@@ -345,115 +465,14 @@ impl InventoryPrototype {
     }
 
     fn spell_consumable(&self, rng: &mut ThreadRng) -> Consumable {
-        let spell_names: Vec<SpellName> = SpellName::iter().collect();
-        let index = rng.gen_range(0..spell_names.len());
-        let spell_name = spell_names.get(index).unwrap();
-
-        let spell_uses: i32 = self.spell_uses(rng, spell_name);
-
-        let spell_attack = if matches!(
-            spell_name,
-            SpellName::RagingFireball | SpellName::ElectricBlast
-        ) {
-            let num_rolls = if (1..=10).contains(&self.danger_level) {
-                2
-            } else if (11..=25).contains(&self.danger_level) {
-                5
-            } else if (26..=40).contains(&self.danger_level) {
-                9
-            } else if (41..=60).contains(&self.danger_level) {
-                15
-            } else {
-                20
-            };
-            Some(Attack {
-                num_rolls,
-                modifier: 0,
-                effects: Vec::new(),
-            })
-        } else if spell_name == &SpellName::Retribution {
-            let num_rolls = if (1..=10).contains(&self.danger_level) {
-                3
-            } else if (11..=25).contains(&self.danger_level) {
-                4
-            } else if (26..=40).contains(&self.danger_level) {
-                6
-            } else if (41..=60).contains(&self.danger_level) {
-                8
-            } else {
-                10
-            };
-
-            Some(Attack {
-                num_rolls,
-                modifier: -1,
-                effects: Vec::new(),
-            })
-        } else if spell_name == &SpellName::QuickHeal {
-            let num_rolls = if (1..=10).contains(&self.danger_level) {
-                1
-            } else if (11..=25).contains(&self.danger_level) {
-                2
-            } else if (26..=40).contains(&self.danger_level) {
-                3
-            } else if (41..=60).contains(&self.danger_level) {
-                4
-            } else {
-                5
-            };
-            Some(Attack {
-                num_rolls,
-                modifier: 0,
-                effects: Vec::new(),
-            })
-        } else if spell_name == &SpellName::Heal {
-            let num_rolls = if (1..=10).contains(&self.danger_level) {
-                2
-            } else if (11..=25).contains(&self.danger_level) {
-                4
-            } else if (26..=40).contains(&self.danger_level) {
-                6
-            } else if (41..=60).contains(&self.danger_level) {
-                8
-            } else {
-                10
-            };
-
-            Some(Attack {
-                num_rolls,
-                modifier: 0,
-                effects: Vec::new(),
-            })
-        } else {
-            None
-        };
-
-        let spell_defense = if spell_name == &SpellName::TinyShield {
-            let damage_resistance = if (1..=10).contains(&self.danger_level) {
-                rng.gen_range(2..=10)
-            } else if (11..=25).contains(&self.danger_level) {
-                rng.gen_range(5..=20)
-            } else if (26..=40).contains(&self.danger_level) {
-                rng.gen_range(10..=30)
-            } else if (41..=60).contains(&self.danger_level) {
-                rng.gen_range(15..=40)
-            } else {
-                rng.gen_range(20..=50)
-            };
-            Some(Defense { damage_resistance })
-        } else {
-            None
-        };
-
         Consumable {
             effect: ConsumableEffect {
                 name: ConsumableEffectName::LearnSpell,
-                learn_spell_effect: Some(LearnSpellEffect {
-                    spell_name: *spell_name,
-                    spell_attack,
-                    spell_defense,
-                    spell_uses,
-                }),
+                learn_spell_effect: Some(learn_spell_effect(
+                    rng,
+                    self.danger_level,
+                    &self.content_pack,
+                )),
                 healing_effect: None,
             },
             uses: 1,
@@ -475,7 +494,7 @@ impl InventoryPrototype {
             ConsumableEffectName::HealingGrog => ItemType::Flask,
         };
 
-        let possible_materials = super::utils::materials::possible_materials(&item_type);
+        let possible_materials = self.content_pack.materials(&item_type);
         let material = if possible_materials.is_empty() {
             None
         } else {
@@ -487,6 +506,7 @@ impl InventoryPrototype {
             &item_type,
             &material,
             self.danger_level,
+            &self.content_pack,
         );
         let descriptors = if possible_descriptors.is_empty() {
             Vec::new()
@@ -508,6 +528,8 @@ impl InventoryPrototype {
                 defense: None,
                 consumable: Some(consumable),
                 throwable: None,
+                durability: None,
+                affixes: Vec::new(),
             },
             equipped_location: LocationTag::Packed,
             at_the_ready: false,
@@ -581,7 +603,9 @@ fn item_type_is_for_tags(item_type: &ItemType, tag: &LocationTag) -> bool {
         ItemType::Mask => tag.eq(&LocationTag::Face),
         ItemType::Shackles => tag.eq(&LocationTag::Wrist) | tag.eq(&LocationTag::Ankle),
         ItemType::Trousers => tag.eq(&LocationTag::Leg),
-        ItemType::Scroll => tag.eq(&LocationTag::Packed) | tag.eq(&LocationTag::Pockets),
+        ItemType::Scroll | ItemType::Key => {
+            tag.eq(&LocationTag::Packed) | tag.eq(&LocationTag::Pockets)
+        }
         ItemType::Pot => tag.eq(&LocationTag::Packed),
         ItemType::Flask => tag.eq(&LocationTag::Packed),
     }
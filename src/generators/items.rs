@@ -1,35 +1,122 @@
 use rand::{prelude::ThreadRng, Rng};
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 use uuid::Uuid;
 
-use crate::components::{
-    damage::AttackEffect,
-    items::{Descriptor, Item, ItemType},
-    Material, Tagged, {Attack, Defense},
+use crate::{
+    components::{
+        damage::AttackEffect,
+        items::{Affix, Descriptor, Durability, Item, ItemType},
+        Material, Tagged, {Attack, Defense},
+    },
+    utils::rolls::roll_percent_succeeds,
 };
 
-use super::generator::Generator;
+use super::{generator::Generator, name::generate_item_name, ContentPack};
 
 pub fn item_generator_for_level(
     item_type: &ItemType,
     is_equipped: bool,
     level: u32,
+    content_pack: &Arc<ContentPack>,
 ) -> impl Generator<Item> {
     ItemPrototype {
         item_type: *item_type,
         num_descriptors: 1..=2,
-        materials: super::utils::materials::possible_materials(item_type),
+        materials: content_pack.materials(item_type),
         is_equipped,
         item_level: Some(level),
+        content_pack: content_pack.clone(),
     }
 }
 
+const BASE_DURABILITY: i32 = 20;
+const AFFIX_CHANCE_PER_LEVEL: i32 = 2;
+const MAX_AFFIX_CHANCE: i32 = 60;
+const SECOND_AFFIX_LEVEL: u32 = 30;
+const CURSE_CHANCE: i32 = 25;
+
+/// Equippable items get durability from what they are made of, knocked down by any
+/// descriptors that show they have seen better days.
+pub fn starting_durability(
+    item_type: &ItemType,
+    material: &Option<Material>,
+    descriptors: &[Descriptor],
+) -> Option<Durability> {
+    if !item_type.tags().iter().any(|tag| tag.is_equippable()) {
+        return None;
+    }
+
+    let max = material
+        .map(|material| material.durability())
+        .unwrap_or(BASE_DURABILITY);
+    let current = descriptors
+        .iter()
+        .fold(max, |current, descriptor| {
+            current * descriptor.durability_percent() / 100
+        })
+        .max(1);
+
+    Some(Durability { current, max })
+}
+
+fn with_descriptor_effects(attack: Attack, descriptors: &[Descriptor]) -> Attack {
+    let mut effects: Vec<AttackEffect> = attack
+        .effects
+        .into_iter()
+        .chain(
+            descriptors
+                .iter()
+                .filter_map(|descriptor| descriptor.attack_effect()),
+        )
+        .collect();
+    effects.sort();
+    effects.dedup();
+
+    Attack { effects, ..attack }
+}
+
+fn enchantments(attack: &Option<Attack>, defense: &Option<Defense>) -> Vec<Affix> {
+    let mut enchantments: Vec<Affix> = Vec::new();
+    if attack.is_some() {
+        enchantments.append(&mut vec![
+            Affix::Empowered,
+            Affix::Corrosive,
+            Affix::Venomous,
+            Affix::LifeStealing,
+        ]);
+    }
+    if defense.is_some() {
+        enchantments.append(&mut vec![Affix::Warded, Affix::Regenerating]);
+    }
+    enchantments
+}
+
+/// Adds an enchantment the item doesn't already have, naming it if it wasn't
+/// named before. Returns false if there was nothing left to add.
+pub fn enchant(item: &mut Item) -> bool {
+    let mut possible_affixes = enchantments(&item.attack, &item.defense);
+    possible_affixes.retain(|affix| !item.affixes.contains(affix));
+    if possible_affixes.is_empty() {
+        return false;
+    }
+
+    let mut rng = rand::thread_rng();
+    let index = rng.gen_range(0..possible_affixes.len());
+    item.affixes.push(possible_affixes.remove(index));
+    if item.name.is_none() {
+        item.name = Some(generate_item_name(&item.item_type));
+    }
+
+    true
+}
+
 pub struct ItemPrototype {
     pub item_type: ItemType,
     pub num_descriptors: RangeInclusive<usize>,
     pub materials: Vec<Material>,
     pub is_equipped: bool,
     pub item_level: Option<u32>,
+    pub content_pack: Arc<ContentPack>,
 }
 
 impl Generator<Item> for ItemPrototype {
@@ -37,13 +124,22 @@ impl Generator<Item> for ItemPrototype {
         let mut rng = rand::thread_rng();
         let material = self.material();
         let descriptors = self.descriptors(&material);
-        let attack = self.attack(&mut rng);
+        let attack = self
+            .attack(&mut rng)
+            .map(|attack| with_descriptor_effects(attack, &descriptors));
         let defense = self.defense(&mut rng);
         let tags = self.item_type.tags();
+        let durability = starting_durability(&self.item_type, &material, &descriptors);
+        let affixes = self.affixes(&attack, &defense, &mut rng);
+        let name = if affixes.is_empty() {
+            None
+        } else {
+            Some(generate_item_name(&self.item_type))
+        };
 
         Item {
             id: Uuid::new_v4(),
-            name: None,
+            name,
             item_type: self.item_type,
             tags,
             descriptors,
@@ -52,6 +148,8 @@ impl Generator<Item> for ItemPrototype {
             defense,
             consumable: None,
             throwable: None,
+            durability,
+            affixes,
         }
     }
 }
@@ -82,6 +180,7 @@ impl ItemPrototype {
                 &self.item_type,
                 material,
                 self.level(),
+                &self.content_pack,
             );
         let descriptors = num_descriptor_range.filter_map(|_| {
             if possible_descriptors.is_empty() {
@@ -120,6 +219,7 @@ impl ItemPrototype {
             | ItemType::Flask
             | ItemType::Gloves
             | ItemType::Helm
+            | ItemType::Key
             | ItemType::LoinCloth
             | ItemType::Mask
             | ItemType::PlateBoots
@@ -245,6 +345,7 @@ impl ItemPrototype {
             | ItemType::GreatSword
             | ItemType::Halberd
             | ItemType::Hammer
+            | ItemType::Key
             | ItemType::LongSword
             | ItemType::Mace
             | ItemType::Morningstar
@@ -276,6 +377,44 @@ impl ItemPrototype {
         })
     }
 
+    /// Magic gets more common the deeper the danger goes, and some of it is
+    /// best left alone.
+    fn affixes(
+        &self,
+        attack: &Option<Attack>,
+        defense: &Option<Defense>,
+        rng: &mut ThreadRng,
+    ) -> Vec<Affix> {
+        if attack.is_none() && defense.is_none() {
+            return Vec::new();
+        }
+
+        let level = self.level();
+        let affix_chance = (level as i32 * AFFIX_CHANCE_PER_LEVEL).min(MAX_AFFIX_CHANCE);
+        let num_rolls = if level > SECOND_AFFIX_LEVEL { 2 } else { 1 };
+
+        let mut affixes: Vec<Affix> = Vec::new();
+        for _ in 0..num_rolls {
+            if !roll_percent_succeeds(rng, affix_chance) {
+                continue;
+            }
+
+            let mut possible_affixes: Vec<Affix> = if roll_percent_succeeds(rng, CURSE_CHANCE) {
+                vec![Affix::Binding, Affix::Festering, Affix::Beckoning]
+            } else {
+                enchantments(attack, defense)
+            };
+            possible_affixes.retain(|affix| !affixes.contains(affix));
+
+            if !possible_affixes.is_empty() {
+                let index = rng.gen_range(0..possible_affixes.len());
+                affixes.push(possible_affixes.remove(index));
+            }
+        }
+
+        affixes
+    }
+
     fn level(&self) -> u32 {
         self.item_level.unwrap_or(1)
     }
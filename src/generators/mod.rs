@@ -1,4 +1,6 @@
 pub mod characters;
+pub mod content_pack;
+pub mod dungeons;
 pub mod fixtures;
 pub mod game;
 pub mod generator;
@@ -7,12 +9,16 @@ pub mod items;
 pub mod name;
 pub mod non_players;
 pub mod players;
+pub mod quests;
 pub mod rooms;
 pub mod stats;
 mod utils;
 
 pub use characters::CharacterGeneratorBuilder;
+pub use content_pack::ContentPack;
+pub use dungeons::{Dungeon, DungeonGeneratorBuilder};
 pub use inventory::InventoryGeneratorBuilder;
+pub use name::{MarkovNameModel, NameStyle};
 pub use rooms::{
     ExitGenerationArgs, RoomFixtureGenerationArgs, RoomGeneratorBuilder, RoomNpcGenerationArgs,
 };
@@ -1,18 +1,187 @@
-use rand::Rng;
+use std::collections::HashMap;
 
-use crate::utils::rolls::roll_percent_succeeds;
+use rand::{prelude::ThreadRng, Rng};
+
+use crate::{
+    components::{items::ItemType, rooms::RoomType, LifeModifier, Rank, Species, Tag, Tagged},
+    utils::rolls::roll_percent_succeeds,
+};
+
+use super::content_pack::ContentPack;
 
 const CHANCE_UNNAMED: i32 = 10;
+const EPITHET_CHANCE: i32 = 50;
+const MARKOV_ORDER: usize = 2;
+const MARKOV_MIN_LENGTH: usize = 3;
+const MARKOV_MAX_LENGTH: usize = 10;
+const MARKOV_ATTEMPTS: usize = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NameStyle {
+    Common,
+    Guttural,
+    Sibilant,
+    Echoing,
+}
+
+impl NameStyle {
+    pub fn for_species(species: &Species) -> Self {
+        match *species {
+            Species::Bugbear | Species::Ogre | Species::Orc | Species::Rockoblin => {
+                NameStyle::Guttural
+            }
+            Species::Dragonkin | Species::Lizardkin => NameStyle::Sibilant,
+            Species::Phantom | Species::Shadow => NameStyle::Echoing,
+            Species::Frogkin
+            | Species::Goblin
+            | Species::Hobgoblin
+            | Species::Kobold
+            | Species::Moblin
+            | Species::Turtlekin => NameStyle::Common,
+        }
+    }
+
+    fn parts(&self) -> Vec<&'static str> {
+        match *self {
+            NameStyle::Common => vec![
+                "gon", "gro", "grub", "num", "gorg", "zerg", "and", "por", "mer", "mog", "og",
+                "zola", "mar", "ar", "la", "zo", "mank", "mang", "grap", "log", "lorg", "glor",
+                "bor", "bob", "plo", "nor", "norn", "mac", "oor", "onk", "rok", "ron", "car",
+                "toe", "pan", "flick", "lerg", "wod", "saw", "grum", "crum", "rin", "bal", "rog",
+            ],
+            NameStyle::Guttural => vec![
+                "grok", "thrak", "urk", "dur", "gash", "bruk", "krag", "murg", "zug", "drog",
+                "gor", "hurg", "narg", "rukh", "vorg", "grum", "skul", "brak", "ug", "torg",
+            ],
+            NameStyle::Sibilant => vec![
+                "ss", "sith", "zass", "ess", "sis", "shi", "ssk", "xiss", "sa", "zyth", "ith",
+                "sasz", "hiss", "syl", "thess", "iss", "zar", "esh", "suul", "sek",
+            ],
+            NameStyle::Echoing => vec![
+                "vael", "mor", "rel", "sol", "wen", "ith", "lor", "nim", "ael", "ova", "umbra",
+                "thal", "eth", "sil", "yr", "ost", "anu", "el",
+            ],
+        }
+    }
 
+    fn num_parts(&self) -> std::ops::RangeInclusive<usize> {
+        match *self {
+            NameStyle::Common | NameStyle::Guttural => 1..=3,
+            NameStyle::Sibilant => 2..=3,
+            NameStyle::Echoing => 1..=2,
+        }
+    }
+}
+
+/// Name in the common style, which is what every NPC used to get no matter
+/// their species.
+#[deprecated(note = "use generate_npc_name, which names NPCs after their species")]
 pub fn generate_name() -> Option<String> {
+    generate_npc_name(&Species::Goblin, &None, &ContentPack::default())
+}
+
+/// Name for an NPC of the given species. If the content pack has a word list
+/// for the species, a Markov model trained on it is used instead of the
+/// built-in syllables.
+pub fn generate_npc_name(
+    species: &Species,
+    life_modifier: &Option<LifeModifier>,
+    content_pack: &ContentPack,
+) -> Option<String> {
     let mut rng = rand::thread_rng();
     if roll_percent_succeeds(&mut rng, CHANCE_UNNAMED) {
         return None;
     }
 
-    let num_parts: usize = rng.gen_range(1..=3);
+    let style = NameStyle::for_species(species);
+    let words = content_pack.name_words(species);
+    let base = MarkovNameModel::train(&words, MARKOV_ORDER)
+        .generate(&mut rng, MARKOV_MIN_LENGTH, MARKOV_MAX_LENGTH)
+        .unwrap_or_else(|| styled_name(&mut rng, &style));
+
+    let name = if style == NameStyle::Echoing {
+        echoing_title(&mut rng, &base)
+    } else {
+        base
+    };
+
+    match life_modifier {
+        Some(modifier) if roll_percent_succeeds(&mut rng, EPITHET_CHANCE) => {
+            Some(format!("{} {}", name, epithet(modifier)))
+        }
+        _ => Some(name),
+    }
+}
+
+pub fn generate_room_name(room_type: &RoomType) -> String {
+    let mut rng = rand::thread_rng();
+    let adjectives = [
+        "Forgotten",
+        "Sunken",
+        "Weeping",
+        "Hollow",
+        "Crooked",
+        "Silent",
+        "Bleak",
+        "Rotten",
+        "Gnawed",
+        "Smouldering",
+    ];
+    let nouns = room_nouns(room_type);
 
-    let mut all_name_parts = name_parts();
+    format!(
+        "The {} {}",
+        choose(&mut rng, &adjectives),
+        choose(&mut rng, &nouns)
+    )
+}
+
+/// Named items belong to someone, or at least used to.
+pub fn generate_item_name(item_type: &ItemType) -> String {
+    let mut rng = rand::thread_rng();
+    let owner = styled_name(&mut rng, &NameStyle::Common);
+    let tags = item_type.tags();
+    let nouns = if tags.contains(&Tag::Blade) {
+        vec!["Edge", "Fang", "Tooth", "Sting"]
+    } else if tags.contains(&Tag::Blunt) {
+        vec!["Maul", "Fist", "Knell", "Crusher"]
+    } else if tags.contains(&Tag::Shield) {
+        vec!["Bulwark", "Wall", "Ward"]
+    } else if tags.contains(&Tag::Armour) || tags.contains(&Tag::Clothing) {
+        vec!["Hide", "Mantle", "Shroud", "Skin"]
+    } else {
+        vec!["Keepsake", "Trinket", "Curio"]
+    };
+
+    format!("{}'s {}", owner, choose(&mut rng, &nouns))
+}
+
+pub fn generate_npc_title(rank: &Rank) -> String {
+    let mut rng = rand::thread_rng();
+    let titles = match *rank {
+        Rank::Elite => vec![
+            "the Brute",
+            "the Cruel",
+            "the Scarred",
+            "Sergeant",
+            "the Sly",
+        ],
+        Rank::Boss => vec![
+            "the Butcher",
+            "Warlord",
+            "the Unbroken",
+            "Lord of the Deep",
+            "the Devourer",
+        ],
+    };
+
+    choose(&mut rng, &titles).to_string()
+}
+
+fn styled_name(rng: &mut ThreadRng, style: &NameStyle) -> String {
+    let num_parts: usize = rng.gen_range(style.num_parts());
+
+    let mut all_name_parts = style.parts();
     let name_parts: Vec<&str> = (0..num_parts)
         .map(|_| {
             let index = rng.gen_range(0..all_name_parts.len());
@@ -20,14 +189,158 @@ pub fn generate_name() -> Option<String> {
         })
         .collect();
 
-    Some(name_parts.join(""))
+    capitalize(&name_parts.join(""))
+}
+
+fn echoing_title(rng: &mut ThreadRng, name: &str) -> String {
+    let titles = ["Echo", "Whisper", "Shade", "Murmur", "Remnant"];
+    let echo: String = name
+        .chars()
+        .rev()
+        .take(3)
+        .collect::<Vec<char>>()
+        .into_iter()
+        .rev()
+        .collect();
+
+    format!(
+        "{} of {}-{}",
+        choose(rng, &titles),
+        name,
+        echo.to_lowercase()
+    )
+}
+
+fn epithet(life_modifier: &LifeModifier) -> &'static str {
+    match *life_modifier {
+        LifeModifier::Ghost => "the Restless",
+        LifeModifier::Skeleton => "the Hollow",
+        LifeModifier::Vampire => "the Pale",
+        LifeModifier::Zombie => "the Rotting",
+    }
 }
 
-fn name_parts() -> Vec<&'static str> {
-    vec![
-        "gon", "gro", "grub", "num", "gorg", "zerg", "and", "por", "mer", "mog", "og", "zola",
-        "mar", "ar", "la", "zo", "mank", "mang", "grap", "log", "lorg", "glor", "bor", "bob",
-        "plo", "nor", "norn", "mac", "oor", "onk", "rok", "ron", "car", "toe", "pan", "flick",
-        "lerg", "wod", "saw", "grum", "crum", "rin", "bal", "rog",
-    ]
+fn room_nouns(room_type: &RoomType) -> Vec<&'static str> {
+    match *room_type {
+        RoomType::Cave | RoomType::Cavern => vec!["Grotto", "Hollow", "Maw", "Deep"],
+        RoomType::Cemetery => vec!["Boneyard", "Graves", "Barrows"],
+        RoomType::Crypt | RoomType::Mausoleum => vec!["Tomb", "Vault", "Ossuary"],
+        RoomType::EntryWay => vec!["Threshold", "Gate", "Passage"],
+        RoomType::PrisonCell => vec!["Cell", "Oubliette", "Pit"],
+        RoomType::Room => vec!["Chamber", "Den", "Hall"],
+        RoomType::TavernHall => vec!["Tankard", "Alehouse", "Flagon"],
+        RoomType::TempleHall => vec!["Sanctum", "Shrine", "Altar"],
+    }
+}
+
+fn choose(rng: &mut ThreadRng, options: &[&'static str]) -> &'static str {
+    options[rng.gen_range(0..options.len())]
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+const START: char = '^';
+const END: char = '$';
+
+/// Character-level Markov chain trained from a list of words, so names
+/// generated from it keep the feel of the words it was given.
+#[derive(Clone, Debug, Default)]
+pub struct MarkovNameModel {
+    order: usize,
+    transitions: HashMap<String, Vec<char>>,
+}
+
+impl MarkovNameModel {
+    pub fn train<S: AsRef<str>>(words: &[S], order: usize) -> Self {
+        let order = order.max(1);
+        let mut transitions: HashMap<String, Vec<char>> = HashMap::new();
+
+        for word in words {
+            let word = word.as_ref().trim().to_lowercase();
+            if word.is_empty() {
+                continue;
+            }
+
+            let chars: Vec<char> = std::iter::repeat_n(START, order)
+                .chain(word.chars())
+                .chain(std::iter::once(END))
+                .collect();
+
+            for window in chars.windows(order + 1) {
+                let key: String = window[..order].iter().collect();
+                transitions.entry(key).or_default().push(window[order]);
+            }
+        }
+
+        Self { order, transitions }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    pub fn generate(
+        &self,
+        rng: &mut ThreadRng,
+        min_length: usize,
+        max_length: usize,
+    ) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+
+        (0..MARKOV_ATTEMPTS).find_map(|_| {
+            let mut key: String = std::iter::repeat_n(START, self.order).collect();
+            let mut name = String::new();
+
+            loop {
+                let next = match self.transitions.get(&key) {
+                    Some(options) => options[rng.gen_range(0..options.len())],
+                    None => END,
+                };
+
+                if next == END || name.chars().count() >= max_length {
+                    break;
+                }
+
+                name.push(next);
+                key = key.chars().skip(1).chain(std::iter::once(next)).collect();
+            }
+
+            if name.chars().count() >= min_length {
+                Some(capitalize(&name))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarkovNameModel;
+
+    #[test]
+    fn markov_names_stop_at_the_max_length() {
+        let model = MarkovNameModel::train(&["abcdefghijklmnop"], 2);
+
+        let name = model.generate(&mut rand::thread_rng(), 3, 5);
+
+        assert_eq!(Some("Abcde".to_string()), name);
+    }
+
+    #[test]
+    fn markov_names_shorter_than_the_min_length_give_up_after_every_attempt() {
+        let model = MarkovNameModel::train(&["ab", "ba"], 2);
+
+        let name = model.generate(&mut rand::thread_rng(), 3, 10);
+
+        assert_eq!(None, name);
+    }
 }
@@ -1,14 +1,19 @@
+use std::sync::Arc;
+
 use uuid::Uuid;
 
 use crate::components::NonPlayer;
 
-use super::{generator::Generator, name::generate_name, CharacterGeneratorBuilder};
+use super::{
+    generator::Generator, name::generate_npc_name, CharacterGeneratorBuilder, ContentPack,
+};
 
 #[derive(Default, Clone)]
 pub struct NonPlayerGeneratorBuilder {
     name: Option<String>,
     character_gen_builder: Option<CharacterGeneratorBuilder>,
     danger_level: Option<u32>,
+    content_pack: Option<Arc<ContentPack>>,
 }
 
 impl NonPlayerGeneratorBuilder {
@@ -34,25 +39,28 @@ impl NonPlayerGeneratorBuilder {
         self
     }
 
+    pub fn content_pack(&mut self, content_pack: Arc<ContentPack>) -> &mut Self {
+        self.content_pack = Some(content_pack);
+
+        self
+    }
+
     pub fn build(&self) -> impl Generator<NonPlayer> {
         let danger_level = self.danger_level.unwrap_or(1);
+        let content_pack = self.content_pack.clone().unwrap_or_default();
 
         let character_gen_builder = match &self.character_gen_builder {
             Some(builder) => builder.to_owned(),
             None => CharacterGeneratorBuilder::default()
                 .danger_level(danger_level)
+                .content_pack(content_pack.clone())
                 .to_owned(),
         };
 
-        let name = if self.name.is_none() {
-            generate_name()
-        } else {
-            self.name.clone()
-        };
-
         NonPlayerPrototype {
-            name,
+            name: self.name.clone(),
             character_gen_builder,
+            content_pack,
         }
     }
 }
@@ -60,16 +68,27 @@ impl NonPlayerGeneratorBuilder {
 struct NonPlayerPrototype {
     pub name: Option<String>,
     pub character_gen_builder: CharacterGeneratorBuilder,
+    pub content_pack: Arc<ContentPack>,
 }
 
 impl Generator<NonPlayer> for NonPlayerPrototype {
     fn generate(&self) -> NonPlayer {
         let character = self.character_gen_builder.build().generate();
+        let name = match &self.name {
+            Some(name) => Some(name.clone()),
+            None => generate_npc_name(
+                &character.species,
+                &character.life_modifier,
+                &self.content_pack,
+            ),
+        };
 
         NonPlayer {
             character,
             id: Uuid::new_v4(),
-            name: self.name.clone(),
+            name,
+            rank: None,
+            title: None,
         }
     }
 }
@@ -10,7 +10,7 @@ use crate::components::{
     {Attack, Defense},
 };
 
-use super::{generator::Generator, stats::build_specific_health};
+use super::{generator::Generator, items::starting_durability, stats::build_specific_health};
 
 struct PlayerCharacterPrototype {
     pub character_name: Option<String>,
@@ -99,6 +99,12 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        durability: starting_durability(
+            &ItemType::Trousers,
+            &Some(Material::Linen),
+            &[Descriptor::Dirty, Descriptor::Stained],
+        ),
+        affixes: Vec::new(),
     };
 
     let shirt = Item {
@@ -114,6 +120,12 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        durability: starting_durability(
+            &ItemType::Shirt,
+            &Some(Material::Linen),
+            &[Descriptor::Colourful],
+        ),
+        affixes: Vec::new(),
     };
 
     let boots = Item {
@@ -129,6 +141,12 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        durability: starting_durability(
+            &ItemType::Boots,
+            &Some(Material::Leather),
+            &[Descriptor::Dirty],
+        ),
+        affixes: Vec::new(),
     };
 
     vec![
@@ -179,6 +197,8 @@ fn starter_weapon(rng: &mut ThreadRng) -> CharacterItem {
         vec![Descriptor::Rusty]
     };
 
+    let durability = starting_durability(&item_type, &material, &descriptors);
+
     let item = Item {
         id: Uuid::new_v4(),
         name: None,
@@ -194,6 +214,8 @@ fn starter_weapon(rng: &mut ThreadRng) -> CharacterItem {
         defense: None,
         consumable: None,
         throwable: None,
+        durability,
+        affixes: Vec::new(),
     };
 
     CharacterItem {
@@ -0,0 +1,133 @@
+use std::{ops::RangeInclusive, sync::Arc};
+
+use rand::Rng;
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+use crate::components::{
+    items::ItemType,
+    quests::{Objective, Quest, Reward},
+    rooms::Room,
+    spells::Spell,
+    Species,
+};
+
+use super::{
+    generator::Generator,
+    inventory::learn_spell_effect,
+    items::{enchant, item_generator_for_level},
+    name::generate_npc_name,
+    non_players::NonPlayerGeneratorBuilder,
+    CharacterGeneratorBuilder, ContentPack,
+};
+
+const EXTRA_KILLS: RangeInclusive<u32> = 0..=2;
+const DEPTHS_FURTHER: RangeInclusive<u32> = 1..=2;
+const BASE_GOLD: RangeInclusive<u32> = 10..=30;
+const GOLD_PER_DANGER: u32 = 2;
+const REWARD_ITEM_TYPES: [ItemType; 8] = [
+    ItemType::Breastplate,
+    ItemType::Hammer,
+    ItemType::LongSword,
+    ItemType::Mace,
+    ItemType::PlateHelmet,
+    ItemType::Shield,
+    ItemType::ShortSword,
+    ItemType::Spear,
+];
+
+/// A quest that makes sense for a room the player has just seen, such as
+/// hunting the species found there or taking a named item out of one of its
+/// fixtures. Quests to go deeper can be given anywhere.
+pub fn generate_quest(room: &Room, danger_level: u32, content_pack: &Arc<ContentPack>) -> Quest {
+    let mut rng = rand::thread_rng();
+    let mut objectives: Vec<Objective> = Vec::new();
+
+    if let Some(npc) = room.first_alive_npc() {
+        let species = npc.character.species;
+        let num_in_room = room
+            .npc_positions
+            .iter()
+            .filter(|position| {
+                position.npc.character.species == species && !position.npc.character.is_dead()
+            })
+            .count() as u32;
+        objectives.push(Objective::KillSpecies {
+            species,
+            count: num_in_room + rng.gen_range(EXTRA_KILLS),
+        });
+    }
+
+    for position in room.fixture_positions.iter() {
+        for fixture_item in position.fixture.items.iter() {
+            if let Some(item_name) = &fixture_item.item.name {
+                objectives.push(Objective::RetrieveItem {
+                    item_id: fixture_item.item.id,
+                    item_name: item_name.clone(),
+                    fixture_id: position.fixture.id,
+                });
+            }
+        }
+    }
+
+    objectives.push(Objective::ReachDepth {
+        depth: room.depth + rng.gen_range(DEPTHS_FURTHER),
+    });
+
+    let all_species: Vec<Species> = Species::iter().collect();
+    let species = all_species[rng.gen_range(0..all_species.len())];
+    if let Some(npc_name) = generate_npc_name(&species, &None, content_pack) {
+        let npc = NonPlayerGeneratorBuilder::new()
+            .name(&npc_name)
+            .character_gen_builder(
+                CharacterGeneratorBuilder::new()
+                    .species(species)
+                    .has_inventory(false)
+                    .danger_level(danger_level)
+                    .content_pack(content_pack.clone())
+                    .to_owned(),
+            )
+            .content_pack(content_pack.clone())
+            .build()
+            .generate();
+        objectives.push(Objective::EscortNpc {
+            npc: Box::new(npc),
+            depth: room.depth + rng.gen_range(DEPTHS_FURTHER),
+        });
+    }
+
+    let objective = objectives.remove(rng.gen_range(0..objectives.len()));
+
+    Quest {
+        id: Uuid::new_v4(),
+        objective,
+        reward: reward(danger_level, content_pack),
+        progress: 0,
+        completed: false,
+        failed: false,
+    }
+}
+
+fn reward(danger_level: u32, content_pack: &Arc<ContentPack>) -> Reward {
+    let mut rng = rand::thread_rng();
+
+    match rng.gen_range(0..3) {
+        0 => Reward::Gold(rng.gen_range(BASE_GOLD) + danger_level * GOLD_PER_DANGER),
+        1 => {
+            let item_type = REWARD_ITEM_TYPES[rng.gen_range(0..REWARD_ITEM_TYPES.len())];
+            let mut item =
+                item_generator_for_level(&item_type, false, danger_level, content_pack).generate();
+            enchant(&mut item);
+            Reward::Item(Box::new(item))
+        }
+        _ => {
+            let effect = learn_spell_effect(&mut rng, danger_level, content_pack);
+            Reward::Spell(Spell {
+                name: effect.spell_name,
+                attack: effect.spell_attack,
+                defense: effect.spell_defense,
+                uses: effect.spell_uses,
+            })
+        }
+    }
+}
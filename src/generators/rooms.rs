@@ -2,30 +2,42 @@ mod builder;
 mod dimensions;
 mod exits;
 mod fixtures;
+mod locks;
 pub mod npcs;
+mod traps;
 
 pub use builder::{
     ExitGenerationArgs, RoomFixtureGenerationArgs, RoomGeneratorBuilder, RoomNpcGenerationArgs,
 };
+pub use exits::{assign_exit_directions, way_out};
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use rand::Rng;
 use strum::IntoEnumIterator;
 use uuid::Uuid;
 
-use crate::components::{
-    fixtures::FixtureType,
-    rooms::{Descriptor, Dimensions, ExitType, Flavour, Room, RoomType},
-    Ghost, LifeModifier, Species,
+use crate::{
+    components::{
+        fixtures::FixtureType,
+        games::Ruleset,
+        rooms::{Coordinates, Descriptor, Dimensions, ExitType, Flavour, Room, RoomType},
+        Ghost, LifeModifier, Species,
+    },
+    utils::rolls::roll_percent_succeeds,
 };
 
 use self::{
     dimensions::build_dimensions, exits::build_exits, fixtures::build_fixture_positions,
-    npcs::build_npc_positions,
+    locks::add_locks, npcs::build_npc_positions, traps::add_traps,
 };
 
-use super::generator::Generator;
+use super::{content_pack::ContentPack, generator::Generator, name::generate_room_name};
+
+const ROOM_NAMED_CHANCE: i32 = 25;
+const DEFAULT_UNDEAD_CHANCE: i32 = 15;
+const DEFAULT_ELITE_DANGER_LEVEL: u32 = 10;
+const DEFAULT_BOSS_DANGER_LEVEL: u32 = 30;
 
 pub struct BuildNpcsArgs {
     pub num_groups: RangeInclusive<u16>,
@@ -33,6 +45,14 @@ pub struct BuildNpcsArgs {
     pub possible_life_modifiers: Vec<LifeModifier>,
     pub allow_npcs_to_spawn_dead: bool,
     pub ghosts: Vec<Ghost>,
+    pub undead_chance: i32,
+    pub elite_danger_level: u32,
+    pub boss_danger_level: u32,
+    /// Lairs always have a leader, whatever the danger level.
+    pub is_lair: bool,
+    pub spawn_from_ghost_chance: i32,
+    pub keep_species_chance: i32,
+    pub content_pack: Arc<ContentPack>,
 }
 
 impl Default for BuildNpcsArgs {
@@ -43,6 +63,13 @@ impl Default for BuildNpcsArgs {
             possible_life_modifiers: LifeModifier::iter().collect(),
             allow_npcs_to_spawn_dead: true,
             ghosts: Vec::new(),
+            undead_chance: DEFAULT_UNDEAD_CHANCE,
+            elite_danger_level: DEFAULT_ELITE_DANGER_LEVEL,
+            boss_danger_level: DEFAULT_BOSS_DANGER_LEVEL,
+            is_lair: false,
+            spawn_from_ghost_chance: Ruleset::normal().spawn_from_ghost_chance,
+            keep_species_chance: Ruleset::normal().keep_species_chance,
+            content_pack: Arc::default(),
         }
     }
 }
@@ -50,6 +77,7 @@ impl Default for BuildNpcsArgs {
 pub struct BuildFixturesArgs {
     pub num_groups: RangeInclusive<u16>,
     pub possible_types: Vec<FixtureType>,
+    pub content_pack: Arc<ContentPack>,
 }
 
 impl Default for BuildFixturesArgs {
@@ -57,6 +85,7 @@ impl Default for BuildFixturesArgs {
         Self {
             num_groups: 1..=2,
             possible_types: FixtureType::iter().collect(),
+            content_pack: Arc::default(),
         }
     }
 }
@@ -70,7 +99,9 @@ impl Default for BuildExitArgs {
     fn default() -> Self {
         Self {
             num_exits: 2..=3,
-            exit_types: ExitType::iter().collect(),
+            exit_types: ExitType::iter()
+                .filter(|exit_type| exit_type.can_be_generated())
+                .collect(),
         }
     }
 }
@@ -88,6 +119,8 @@ struct RoomPrototype {
     pub build_exit_args: BuildExitArgs,
     pub build_npc_args: BuildNpcsArgs,
     pub build_fixtures_args: BuildFixturesArgs,
+    pub depth: u32,
+    pub coordinates: Coordinates,
 }
 
 impl Generator<Room> for RoomPrototype {
@@ -105,6 +138,9 @@ impl Generator<Room> for RoomPrototype {
                 }
                 let index = rng.gen_range(0..possible_descriptors.len());
                 let descriptor = possible_descriptors.remove(index);
+                possible_descriptors.retain(|other| {
+                    descriptor.can_be_used_with(other) && other.can_be_used_with(&descriptor)
+                });
                 descriptors.push(descriptor);
             }
         }
@@ -122,11 +158,17 @@ impl Generator<Room> for RoomPrototype {
             self.danger_level,
         );
 
-        Room {
+        let mut room = Room {
             dimensions: self.dimensions.clone().unwrap_or_else(build_dimensions),
             descriptors,
             id: Uuid::new_v4(),
-            name: self.name.clone(),
+            name: self.name.clone().or_else(|| {
+                if roll_percent_succeeds(&mut rng, ROOM_NAMED_CHANCE) {
+                    Some(generate_room_name(&self.room_type))
+                } else {
+                    None
+                }
+            }),
             room_type: self.room_type,
             fixture_positions,
             npc_positions: build_npc_positions(
@@ -137,17 +179,112 @@ impl Generator<Room> for RoomPrototype {
             flavour,
             exits: build_exits(self.entrance_id, &self.build_exit_args),
             loose_items: Vec::new(),
-        }
+            depth: self.depth,
+            coordinates: self.coordinates,
+            traps: Vec::new(),
+        };
+        add_locks(
+            &mut room,
+            self.entrance_id,
+            self.danger_level,
+            &self.build_fixtures_args.content_pack,
+        );
+        add_traps(&mut room, self.entrance_id, self.danger_level);
+
+        room
     }
 }
 
 impl RoomType {
     fn possible_descriptors(&self) -> Vec<Descriptor> {
-        Descriptor::iter().collect()
+        match *self {
+            RoomType::Cave | RoomType::Cavern => vec![
+                Descriptor::Chill,
+                Descriptor::Dark,
+                Descriptor::Dim,
+                Descriptor::Moist,
+                Descriptor::Freezing,
+                Descriptor::Steamy,
+            ],
+            RoomType::Cemetery => vec![
+                Descriptor::Chill,
+                Descriptor::Dark,
+                Descriptor::Dim,
+                Descriptor::Grimy,
+                Descriptor::Moist,
+                Descriptor::Freezing,
+            ],
+            RoomType::Crypt | RoomType::Mausoleum => vec![
+                Descriptor::Chill,
+                Descriptor::Dark,
+                Descriptor::Dim,
+                Descriptor::Grimy,
+                Descriptor::Freezing,
+            ],
+            RoomType::EntryWay => vec![
+                Descriptor::Chill,
+                Descriptor::Dark,
+                Descriptor::Dim,
+                Descriptor::Grimy,
+            ],
+            RoomType::PrisonCell => vec![
+                Descriptor::Chill,
+                Descriptor::Dark,
+                Descriptor::Dim,
+                Descriptor::Grimy,
+                Descriptor::Moist,
+            ],
+            RoomType::TavernHall => vec![
+                Descriptor::Dim,
+                Descriptor::Grimy,
+                Descriptor::Steamy,
+                Descriptor::Sweltering,
+            ],
+            RoomType::TempleHall => vec![
+                Descriptor::Chill,
+                Descriptor::Dark,
+                Descriptor::Dim,
+                Descriptor::Freezing,
+                Descriptor::Sweltering,
+            ],
+            RoomType::Room => Descriptor::iter().collect(),
+        }
     }
 
     fn possible_flavours(&self) -> Vec<Flavour> {
-        Flavour::iter().collect()
+        match *self {
+            RoomType::Cave | RoomType::Cavern => vec![
+                Flavour::AStrangeBreezeBlows,
+                Flavour::IsSomethingWatchingYou,
+                Flavour::MoldMossCoversWalls,
+                Flavour::SomethingSquishyAllOverFloor,
+                Flavour::YouHearScratchingAllAroundYou,
+            ],
+            RoomType::Cemetery | RoomType::Crypt | RoomType::Mausoleum => vec![
+                Flavour::AStrangeBreezeBlows,
+                Flavour::IsSomethingWatchingYou,
+                Flavour::MoldMossCoversWalls,
+                Flavour::UnseenLightsFlickerWalls,
+                Flavour::YouHearScratchingAllAroundYou,
+            ],
+            RoomType::PrisonCell => vec![
+                Flavour::MoldMossCoversWalls,
+                Flavour::SmellsLikeOldGoblinSocks,
+                Flavour::SomethingSquishyAllOverFloor,
+                Flavour::YouHearScratchingAllAroundYou,
+            ],
+            RoomType::TavernHall => vec![
+                Flavour::IsSomethingWatchingYou,
+                Flavour::SmellsLikeOldGoblinSocks,
+                Flavour::SomethingSquishyAllOverFloor,
+            ],
+            RoomType::TempleHall => vec![
+                Flavour::AStrangeBreezeBlows,
+                Flavour::IsSomethingWatchingYou,
+                Flavour::UnseenLightsFlickerWalls,
+            ],
+            RoomType::EntryWay | RoomType::Room => Flavour::iter().collect(),
+        }
     }
 }
 
@@ -156,8 +293,12 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        components::rooms::{Descriptor, RoomType},
-        generators::generator::Generator,
+        components::{
+            fixtures::FixtureType,
+            rooms::{Descriptor, RoomType},
+            Rank, TrapType,
+        },
+        generators::{generator::Generator, RoomNpcGenerationArgs},
     };
 
     use super::RoomGeneratorBuilder;
@@ -181,4 +322,110 @@ mod tests {
             room.descriptors.get(0).unwrap().clone()
         );
     }
+
+    #[test]
+    fn conflicting_descriptors_are_not_combined() {
+        let generator = RoomGeneratorBuilder::new()
+            .num_descriptors(2..=2)
+            .room_type(RoomType::Cave)
+            .possible_descriptors(vec![Descriptor::Freezing, Descriptor::Sweltering])
+            .build();
+
+        for _ in 0..20 {
+            let room = generator.generate();
+            assert_eq!(1, room.descriptors.len());
+        }
+    }
+
+    #[test]
+    fn keys_are_hidden_in_the_room_with_their_lock() {
+        let entrance_id = Uuid::new_v4();
+        for _ in 0..50 {
+            let room = RoomGeneratorBuilder::new()
+                .entrance_id(entrance_id)
+                .build()
+                .generate();
+
+            let locks = room
+                .exits
+                .iter()
+                .filter_map(|exit| exit.lock.as_ref())
+                .chain(
+                    room.fixture_positions
+                        .iter()
+                        .filter_map(|position| position.fixture.lock.as_ref()),
+                );
+            for lock in locks {
+                let key_id = lock.key_id.unwrap();
+                let on_npc = room.npc_positions.iter().any(|position| {
+                    position
+                        .npc
+                        .character
+                        .inventory
+                        .find_item(&key_id)
+                        .is_some()
+                });
+                let in_fixture = room.fixture_positions.iter().any(|position| {
+                    position
+                        .fixture
+                        .items
+                        .iter()
+                        .any(|fixture_item| fixture_item.item.id.eq(&key_id))
+                });
+                assert!(on_npc || in_fixture);
+            }
+            assert!(!room.find_exit(&entrance_id).unwrap().is_locked());
+        }
+    }
+    #[test]
+    fn entrances_are_never_trapped() {
+        let entrance_id = Uuid::new_v4();
+        for _ in 0..50 {
+            let room = RoomGeneratorBuilder::new()
+                .entrance_id(entrance_id)
+                .build()
+                .generate();
+
+            assert!(room.find_exit(&entrance_id).unwrap().trap.is_none());
+            for position in room.fixture_positions.iter() {
+                if let Some(trap) = &position.fixture.trap {
+                    assert_eq!(TrapType::Needle, trap.trap_type);
+                    assert!(matches!(
+                        position.fixture.fixture_type,
+                        FixtureType::Chest | FixtureType::Coffin
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lairs_are_ruled_by_a_boss_past_the_boss_danger_level() {
+        let room = RoomGeneratorBuilder::new()
+            .room_type(RoomType::Mausoleum)
+            .danger_level(40)
+            .room_npc_generation_args(RoomNpcGenerationArgs {
+                num_groups: Some(1..=1),
+                boss_danger_level: Some(30),
+                ..RoomNpcGenerationArgs::default()
+            })
+            .build()
+            .generate();
+
+        let boss = room.living_boss().unwrap();
+        assert!(boss.title.is_some());
+        assert!(boss
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .any(|character_item| !character_item.item.affixes.is_empty()));
+        assert_eq!(
+            1,
+            room.npc_positions
+                .iter()
+                .filter(|position| position.npc.rank == Some(Rank::Boss))
+                .count()
+        );
+    }
 }
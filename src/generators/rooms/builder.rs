@@ -1,4 +1,4 @@
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use rand::Rng;
 use strum::IntoEnumIterator;
@@ -7,13 +7,17 @@ use uuid::Uuid;
 use crate::{
     components::{
         fixtures::FixtureType,
-        rooms::{Descriptor, Dimensions, ExitType, Flavour, Room, RoomType},
+        games::Ruleset,
+        rooms::{Coordinates, Descriptor, Dimensions, ExitType, Flavour, Room, RoomType},
         Ghost, LifeModifier, Species,
     },
-    generators::generator::Generator,
+    generators::{content_pack::ContentPack, generator::Generator},
 };
 
-use super::{BuildExitArgs, BuildFixturesArgs, BuildNpcsArgs, RoomPrototype};
+use super::{
+    BuildExitArgs, BuildFixturesArgs, BuildNpcsArgs, RoomPrototype, DEFAULT_BOSS_DANGER_LEVEL,
+    DEFAULT_ELITE_DANGER_LEVEL,
+};
 
 #[derive(Default, Clone)]
 pub struct ExitGenerationArgs {
@@ -28,6 +32,14 @@ pub struct RoomNpcGenerationArgs {
     pub possible_life_modifiers: Option<Vec<LifeModifier>>,
     pub allow_npcs_to_spawn_dead: Option<bool>,
     pub ghosts: Option<Vec<Ghost>>,
+    /// Percent chance for each group of NPCs to be undead.
+    pub undead_chance: Option<i32>,
+    /// Danger level from which groups can be led by an elite.
+    pub elite_danger_level: Option<u32>,
+    /// Danger level from which a room can be ruled by a boss.
+    pub boss_danger_level: Option<u32>,
+    /// The game's ruleset, for the chances of ghosts and mixed groups.
+    pub ruleset: Option<Ruleset>,
 }
 
 #[derive(Default, Clone)]
@@ -50,6 +62,9 @@ pub struct RoomGeneratorBuilder {
     exit_generation_args: Option<ExitGenerationArgs>,
     room_npc_generation_args: Option<RoomNpcGenerationArgs>,
     room_fixture_generation_args: Option<RoomFixtureGenerationArgs>,
+    depth: Option<u32>,
+    coordinates: Option<Coordinates>,
+    content_pack: Option<Arc<ContentPack>>,
 }
 
 impl RoomGeneratorBuilder {
@@ -105,6 +120,18 @@ impl RoomGeneratorBuilder {
         self
     }
 
+    pub fn depth(&mut self, depth: u32) -> &mut Self {
+        self.depth = Some(depth);
+
+        self
+    }
+
+    pub fn coordinates(&mut self, coordinates: Coordinates) -> &mut Self {
+        self.coordinates = Some(coordinates);
+
+        self
+    }
+
     pub fn dimensions(&mut self, dimensions: Dimensions) -> &mut Self {
         self.dimensions = Some(dimensions);
 
@@ -135,16 +162,25 @@ impl RoomGeneratorBuilder {
         self
     }
 
+    pub fn content_pack(&mut self, content_pack: Arc<ContentPack>) -> &mut Self {
+        self.content_pack = Some(content_pack);
+
+        self
+    }
+
     pub fn build(&self) -> impl Generator<Room> {
+        let content_pack = self.content_pack.clone().unwrap_or_default();
         let num_descriptors = match &self.num_descriptors {
             Some(it) => it.clone(),
             None => 0..=2,
         };
 
+        let depth = self.depth.unwrap_or(0);
+
         let room_type = match &self.room_type {
             Some(it) => *it,
             None => {
-                let room_types: Vec<RoomType> = RoomType::iter().collect();
+                let room_types = room_types_for_depth(depth);
                 let mut rng = rand::thread_rng();
                 let index = rng.gen_range(0..room_types.len());
                 *room_types.get(index).unwrap()
@@ -161,7 +197,7 @@ impl RoomGeneratorBuilder {
             None => room_type.possible_flavours(),
         };
 
-        let build_exit_args = match &self.exit_generation_args {
+        let mut build_exit_args = match &self.exit_generation_args {
             Some(exit_generation_args) => {
                 let num_exits = match &exit_generation_args.num_exits {
                     Some(it) => it.clone(),
@@ -184,6 +220,13 @@ impl RoomGeneratorBuilder {
             },
         };
 
+        // There is nothing above the first floor to climb up to.
+        if depth == 0 && build_exit_args.exit_types.len() > 1 {
+            build_exit_args
+                .exit_types
+                .retain(|exit_type| exit_type.ne(&ExitType::StaircaseUp));
+        }
+
         let build_npc_args = match &self.room_npc_generation_args {
             Some(room_npc_generation_args) => {
                 let num_groups = match &room_npc_generation_args.num_groups {
@@ -193,7 +236,7 @@ impl RoomGeneratorBuilder {
 
                 let possible_species = match &room_npc_generation_args.possible_species {
                     Some(it) => it.clone(),
-                    None => Species::iter().collect(),
+                    None => possible_species(&room_type),
                 };
 
                 let possible_life_modifiers =
@@ -213,20 +256,44 @@ impl RoomGeneratorBuilder {
                     None => Vec::new(),
                 };
 
+                let ruleset = room_npc_generation_args.ruleset.clone().unwrap_or_default();
+
+                let undead_chance = match &room_npc_generation_args.undead_chance {
+                    Some(it) => *it,
+                    None => undead_chance(&room_type),
+                };
+
                 BuildNpcsArgs {
                     num_groups,
                     possible_species,
                     possible_life_modifiers,
                     allow_npcs_to_spawn_dead,
                     ghosts,
+                    undead_chance,
+                    elite_danger_level: room_npc_generation_args
+                        .elite_danger_level
+                        .unwrap_or(DEFAULT_ELITE_DANGER_LEVEL),
+                    boss_danger_level: room_npc_generation_args
+                        .boss_danger_level
+                        .unwrap_or(DEFAULT_BOSS_DANGER_LEVEL),
+                    is_lair: is_lair(&room_type),
+                    spawn_from_ghost_chance: ruleset.spawn_from_ghost_chance,
+                    keep_species_chance: ruleset.keep_species_chance,
+                    content_pack: content_pack.clone(),
                 }
             }
             None => BuildNpcsArgs {
                 num_groups: num_groups(&room_type),
-                possible_species: Species::iter().collect(),
+                possible_species: possible_species(&room_type),
                 possible_life_modifiers: LifeModifier::iter().collect(),
                 allow_npcs_to_spawn_dead: true,
                 ghosts: Vec::new(),
+                undead_chance: undead_chance(&room_type),
+                elite_danger_level: DEFAULT_ELITE_DANGER_LEVEL,
+                boss_danger_level: DEFAULT_BOSS_DANGER_LEVEL,
+                is_lair: is_lair(&room_type),
+                content_pack: content_pack.clone(),
+                ..BuildNpcsArgs::default()
             },
         };
 
@@ -245,11 +312,13 @@ impl RoomGeneratorBuilder {
                 BuildFixturesArgs {
                     num_groups,
                     possible_types,
+                    content_pack,
                 }
             }
             None => BuildFixturesArgs {
                 num_groups: num_fixture_groups(&room_type),
                 possible_types: possible_fixtures(&room_type),
+                content_pack,
             },
         };
 
@@ -266,10 +335,39 @@ impl RoomGeneratorBuilder {
             build_exit_args,
             build_npc_args,
             build_fixtures_args,
+            depth,
+            coordinates: self.coordinates.unwrap_or_default(),
         }
     }
 }
 
+/// Each floor down leans further into caves and the dead.
+fn room_types_for_depth(depth: u32) -> Vec<RoomType> {
+    match depth {
+        0 => RoomType::iter().collect(),
+        1..=2 => vec![
+            RoomType::Cave,
+            RoomType::Crypt,
+            RoomType::PrisonCell,
+            RoomType::Room,
+            RoomType::TavernHall,
+        ],
+        3..=4 => vec![
+            RoomType::Cave,
+            RoomType::Cavern,
+            RoomType::Cemetery,
+            RoomType::Crypt,
+            RoomType::Mausoleum,
+        ],
+        _ => vec![
+            RoomType::Cavern,
+            RoomType::Cemetery,
+            RoomType::Mausoleum,
+            RoomType::TempleHall,
+        ],
+    }
+}
+
 fn possible_fixtures(room_type: &RoomType) -> Vec<FixtureType> {
     match *room_type {
         RoomType::PrisonCell => vec![
@@ -291,10 +389,102 @@ fn possible_fixtures(room_type: &RoomType) -> Vec<FixtureType> {
             FixtureType::Crate,
             FixtureType::Bucket,
         ],
-        _ => FixtureType::iter().collect(),
+        RoomType::Cave | RoomType::Cavern => vec![
+            FixtureType::Barrel,
+            FixtureType::Bucket,
+            FixtureType::Chest,
+            FixtureType::Crate,
+            FixtureType::SleepingRoll,
+            FixtureType::StatueTentacledMonstrosity,
+        ],
+        RoomType::Cemetery => vec![
+            FixtureType::Coffin,
+            FixtureType::Pillar,
+            FixtureType::StatueTentacledMonstrosity,
+            FixtureType::StatueWarrior,
+        ],
+        RoomType::Crypt | RoomType::Mausoleum => vec![
+            FixtureType::Chest,
+            FixtureType::Coffin,
+            FixtureType::Pillar,
+            FixtureType::StatueTentacledMonstrosity,
+            FixtureType::StatueWarrior,
+        ],
+        RoomType::TempleHall => vec![
+            FixtureType::Chest,
+            FixtureType::Pillar,
+            FixtureType::StatueTentacledMonstrosity,
+            FixtureType::StatueWarrior,
+            FixtureType::Table,
+        ],
+        RoomType::Room => FixtureType::iter().collect(),
     }
 }
 
+fn possible_species(room_type: &RoomType) -> Vec<Species> {
+    match *room_type {
+        RoomType::Cave | RoomType::Cavern => vec![
+            Species::Bugbear,
+            Species::Frogkin,
+            Species::Goblin,
+            Species::Kobold,
+            Species::Lizardkin,
+            Species::Moblin,
+            Species::Ogre,
+            Species::Rockoblin,
+            Species::Turtlekin,
+        ],
+        RoomType::Cemetery | RoomType::Crypt | RoomType::Mausoleum => vec![
+            Species::Bugbear,
+            Species::Goblin,
+            Species::Hobgoblin,
+            Species::Kobold,
+            Species::Orc,
+            Species::Phantom,
+            Species::Shadow,
+        ],
+        RoomType::PrisonCell => vec![
+            Species::Goblin,
+            Species::Hobgoblin,
+            Species::Kobold,
+            Species::Moblin,
+            Species::Orc,
+        ],
+        RoomType::TavernHall => vec![
+            Species::Bugbear,
+            Species::Frogkin,
+            Species::Goblin,
+            Species::Hobgoblin,
+            Species::Kobold,
+            Species::Moblin,
+            Species::Ogre,
+            Species::Orc,
+        ],
+        RoomType::TempleHall => vec![
+            Species::Dragonkin,
+            Species::Hobgoblin,
+            Species::Lizardkin,
+            Species::Orc,
+            Species::Phantom,
+            Species::Shadow,
+        ],
+        RoomType::EntryWay | RoomType::Room => Species::iter().collect(),
+    }
+}
+
+fn undead_chance(room_type: &RoomType) -> i32 {
+    match *room_type {
+        RoomType::Cemetery | RoomType::Crypt | RoomType::Mausoleum => 60,
+        RoomType::TempleHall => 25,
+        _ => 15,
+    }
+}
+
+/// Rooms where the first group of NPCs is always led by an elite or a boss.
+fn is_lair(room_type: &RoomType) -> bool {
+    matches!(*room_type, RoomType::Mausoleum | RoomType::TempleHall)
+}
+
 fn num_exits(room_type: &RoomType) -> RangeInclusive<u16> {
     match *room_type {
         RoomType::PrisonCell => 1..=2,
@@ -336,6 +526,8 @@ fn exit_types(room_type: &RoomType) -> Vec<ExitType> {
             ExitType::HoleInTheFloor,
             ExitType::HoleInTheWall,
         ],
-        _ => ExitType::iter().collect(),
+        _ => ExitType::iter()
+            .filter(|exit_type| exit_type.can_be_generated())
+            .collect(),
     }
 }
@@ -2,7 +2,7 @@ use rand::{prelude::ThreadRng, Rng};
 use uuid::Uuid;
 
 use crate::components::{
-    rooms::{Exit, ExitDescriptor, ExitType},
+    rooms::{Direction, Exit, ExitDescriptor, ExitType},
     Material, Size,
 };
 
@@ -36,12 +36,84 @@ pub fn build_exits(entrance_id: Option<Uuid>, args: &BuildExitArgs) -> Vec<Exit>
                 descriptors,
                 id,
                 name: None,
+                direction: None,
+                lock: None,
+                trap: None,
             }
         })
         .into_iter()
         .collect()
 }
 
+/// Puts each exit in a wall of the room. The entrance goes back the way the
+/// player came, exits that change depth go up or down, and the rest take
+/// whichever walls are free. Exits with no free wall left are removed so
+/// that no two rooms end up on the same cell.
+pub fn assign_exit_directions(
+    exits: &mut Vec<Exit>,
+    entrance: Option<(Uuid, Direction)>,
+    blocked: &[Direction],
+) {
+    let mut rng = rand::thread_rng();
+    let mut used: Vec<Direction> = blocked.to_vec();
+
+    if let Some((entrance_id, direction)) = entrance {
+        if let Some(exit) = exits.iter_mut().find(|exit| exit.id.eq(&entrance_id)) {
+            exit.direction = Some(direction);
+            used.push(direction);
+        }
+    }
+
+    let entrance_id = entrance.map(|(id, _)| id);
+    exits.retain_mut(|exit| {
+        if Some(exit.id) == entrance_id {
+            return true;
+        }
+
+        let direction = match Direction::for_exit_type(&exit.exit_type) {
+            Some(vertical) if !used.contains(&vertical) => Some(vertical),
+            Some(_) => None,
+            None => {
+                let free: Vec<Direction> = Direction::flat()
+                    .into_iter()
+                    .filter(|direction| !used.contains(direction))
+                    .collect();
+                if free.is_empty() {
+                    None
+                } else {
+                    free.get(rng.gen_range(0..free.len())).cloned()
+                }
+            }
+        };
+
+        match direction {
+            Some(direction) => {
+                exit.direction = Some(direction);
+                used.push(direction);
+                true
+            }
+            None => false,
+        }
+    });
+}
+
+/// The exit out of the underworld, for games that can be won by escaping.
+pub fn way_out() -> Exit {
+    let mut rng = rand::thread_rng();
+
+    Exit {
+        id: Uuid::new_v4(),
+        name: None,
+        exit_type: ExitType::WayOut,
+        material: None,
+        descriptors: Vec::new(),
+        size: size(&mut rng, &ExitType::WayOut),
+        direction: None,
+        lock: None,
+        trap: None,
+    }
+}
+
 fn material(rng: &mut ThreadRng, exit_type: &ExitType) -> Option<Material> {
     let possible_materials: Vec<Material> = match *exit_type {
         ExitType::Door | ExitType::StaircaseUp | ExitType::StaircaseDown => vec![
@@ -53,9 +125,11 @@ fn material(rng: &mut ThreadRng, exit_type: &ExitType) -> Option<Material> {
         ],
         ExitType::HoleInTheWall
         | ExitType::OpeningToTheVoid
+        | ExitType::HoleInTheCeiling
         | ExitType::HoleInTheFloor
         | ExitType::Hallway
-        | ExitType::DugOutTunnelEntrance => return None,
+        | ExitType::DugOutTunnelEntrance
+        | ExitType::WayOut => return None,
     };
 
     let index = rng.gen_range(0..possible_materials.len());
@@ -113,6 +187,7 @@ fn size(rng: &mut ThreadRng, exit_type: &ExitType) -> Option<Size> {
     let possible_sizes: Vec<Size> = match *exit_type {
         ExitType::Door
         | ExitType::HoleInTheWall
+        | ExitType::HoleInTheCeiling
         | ExitType::HoleInTheFloor
         | ExitType::DugOutTunnelEntrance => vec![
             Size::Average,
@@ -131,6 +206,7 @@ fn size(rng: &mut ThreadRng, exit_type: &ExitType) -> Option<Size> {
             vec![Size::Long, Size::Narrow, Size::Massive, Size::Huge]
         }
         ExitType::Hallway => vec![Size::Long, Size::Short, Size::Wide, Size::Narrow],
+        ExitType::WayOut => vec![Size::Narrow, Size::Small, Size::Tiny],
     };
 
     let index = rng.gen_range(0..possible_sizes.len());
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use rand::Rng;
 
 use crate::{
@@ -5,7 +7,9 @@ use crate::{
         fixtures::{Fixture, FixtureType},
         rooms::{FixturePosition, FixturePositionDescriptor, RoomType},
     },
-    generators::{fixtures::get_generator_for_level, generator::Generator},
+    generators::{
+        content_pack::ContentPack, fixtures::get_generator_for_level, generator::Generator,
+    },
     utils::rolls::roll_percent_succeeds,
 };
 
@@ -25,8 +29,11 @@ pub fn build_fixture_positions(
     let mut positions: Vec<FixturePosition> = Vec::new();
     let num_groups = rng.gen_range(build_fixtures_args.num_groups.clone());
     for _ in 0..num_groups {
-        let mut fixture_generators =
-            FixtureGenerators::build(danger_level, build_fixtures_args.possible_types.clone());
+        let mut fixture_generators = FixtureGenerators::build(
+            danger_level,
+            build_fixtures_args.possible_types.clone(),
+            build_fixtures_args.content_pack.clone(),
+        );
 
         let range = 0..group_size(room_type);
 
@@ -115,10 +122,15 @@ struct FixtureGenerators {
     current_index: usize,
     generated_once: bool,
     danger_level: u32,
+    content_pack: Arc<ContentPack>,
 }
 
 impl FixtureGenerators {
-    fn build(danger_level: u32, fixture_types: Vec<FixtureType>) -> Self {
+    fn build(
+        danger_level: u32,
+        fixture_types: Vec<FixtureType>,
+        content_pack: Arc<ContentPack>,
+    ) -> Self {
         let index = if fixture_types.is_empty() {
             0
         } else {
@@ -130,6 +142,7 @@ impl FixtureGenerators {
             danger_level,
             current_index: index,
             generated_once: false,
+            content_pack,
         }
     }
 
@@ -145,6 +158,7 @@ impl FixtureGenerators {
                 fixture_type,
                 has_hidden_compartment(fixture_type),
                 self.danger_level,
+                &self.content_pack,
             ));
         }
 
@@ -155,12 +169,14 @@ impl FixtureGenerators {
                 &FixtureType::Chair,
                 has_hidden_compartment(&FixtureType::Chair),
                 self.danger_level,
+                &self.content_pack,
             ));
         } else if last_generated == &FixtureType::Barrel && roll_percent_succeeds(&mut rng, 75) {
             return Some(get_generator_for_level(
                 &FixtureType::Crate,
                 has_hidden_compartment(&FixtureType::Crate),
                 self.danger_level,
+                &self.content_pack,
             ));
         }
 
@@ -170,6 +186,7 @@ impl FixtureGenerators {
                 last_generated,
                 has_hidden_compartment(last_generated),
                 self.danger_level,
+                &self.content_pack,
             ));
         }
 
@@ -180,6 +197,7 @@ impl FixtureGenerators {
             fixture_type,
             has_hidden_compartment(fixture_type),
             self.danger_level,
+            &self.content_pack,
         ))
     }
 }
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use rand::{prelude::ThreadRng, Rng};
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        items::{CharacterItem, FixtureItem, Item, ItemType, LocationTag},
+        rooms::Room,
+        Lock,
+    },
+    generators::{
+        content_pack::ContentPack, generator::Generator, items::item_generator_for_level,
+    },
+    utils::rolls::roll_percent_succeeds,
+};
+
+const LOCKED_EXIT_CHANCE: i32 = 15;
+const LOCKED_FIXTURE_CHANCE: i32 = 20;
+const BASE_LOCK_DIFFICULTY: i32 = 8;
+const MAX_LOCK_DIFFICULTY: i32 = 16;
+const DANGER_PER_DIFFICULTY: u32 = 10;
+
+/// Locks some of the exits and fixtures of a freshly generated room. A lock is
+/// only added when its key can be hidden somewhere in the same room, either on
+/// an NPC or in a fixture's hidden compartment, so nothing gets locked away for good.
+pub fn add_locks(
+    room: &mut Room,
+    entrance_id: Option<Uuid>,
+    danger_level: u32,
+    content_pack: &Arc<ContentPack>,
+) {
+    let mut rng = rand::thread_rng();
+    let difficulty = (BASE_LOCK_DIFFICULTY + (danger_level / DANGER_PER_DIFFICULTY) as i32)
+        .min(MAX_LOCK_DIFFICULTY);
+
+    let exit_ids: Vec<Uuid> = room
+        .exits
+        .iter()
+        .filter(|exit| Some(exit.id) != entrance_id && exit.exit_type.can_be_used())
+        .map(|exit| exit.id)
+        .collect();
+    for exit_id in exit_ids {
+        if !roll_percent_succeeds(&mut rng, LOCKED_EXIT_CHANCE) {
+            continue;
+        }
+
+        let key = key(danger_level, content_pack);
+        let key_id = key.id;
+        if hide_key(&mut rng, room, key, None) {
+            if let Some(exit) = room.exits.iter_mut().find(|exit| exit.id.eq(&exit_id)) {
+                exit.lock = Some(Lock {
+                    locked: true,
+                    difficulty,
+                    key_id: Some(key_id),
+                });
+            }
+        }
+    }
+
+    let fixture_ids: Vec<Uuid> = room
+        .fixture_positions
+        .iter()
+        .filter(|position| position.fixture.can_be_opened)
+        .map(|position| position.fixture.id)
+        .collect();
+    for fixture_id in fixture_ids {
+        if !roll_percent_succeeds(&mut rng, LOCKED_FIXTURE_CHANCE) {
+            continue;
+        }
+
+        let key = key(danger_level, content_pack);
+        let key_id = key.id;
+        if hide_key(&mut rng, room, key, Some(fixture_id)) {
+            if let Some(position) = room.find_fixture_mut(&fixture_id) {
+                position.fixture.lock = Some(Lock {
+                    locked: true,
+                    difficulty,
+                    key_id: Some(key_id),
+                });
+            }
+        }
+    }
+}
+
+fn key(danger_level: u32, content_pack: &Arc<ContentPack>) -> Item {
+    item_generator_for_level(&ItemType::Key, false, danger_level.max(1), content_pack).generate()
+}
+
+/// Gives the key to a living NPC or puts it in a hidden compartment, other than
+/// the one of the fixture it opens. Returns false if there was nowhere to put it.
+fn hide_key(rng: &mut ThreadRng, room: &mut Room, key: Item, locked_fixture: Option<Uuid>) -> bool {
+    let num_npcs = room
+        .npc_positions
+        .iter()
+        .filter(|position| !position.npc.character.is_dead())
+        .count();
+    let compartments: Vec<Uuid> = room
+        .fixture_positions
+        .iter()
+        .filter(|position| position.fixture.has_hidden_compartment)
+        .filter(|position| Some(position.fixture.id) != locked_fixture)
+        .map(|position| position.fixture.id)
+        .collect();
+
+    let total = num_npcs + compartments.len();
+    if total == 0 {
+        return false;
+    }
+
+    let choice = rng.gen_range(0..total);
+    if choice < num_npcs {
+        if let Some(position) = room
+            .npc_positions
+            .iter_mut()
+            .filter(|position| !position.npc.character.is_dead())
+            .nth(choice)
+        {
+            position.npc.character.inventory.add_item(CharacterItem {
+                item: key,
+                equipped_location: LocationTag::Pockets,
+                at_the_ready: false,
+            });
+        }
+    } else if let Some(position) = room.find_fixture_mut(&compartments[choice - num_npcs]) {
+        position.fixture.items.push(FixtureItem {
+            item: key,
+            is_inside: false,
+            is_in_hidden_compartment: true,
+        });
+    }
+
+    true
+}
@@ -1,14 +1,18 @@
-use rand::Rng;
+use std::sync::Arc;
+
+use rand::{prelude::ThreadRng, Rng};
 use uuid::Uuid;
 
 use crate::{
     components::{
+        attributes::MAX_ATTRIBUTE,
         fixtures::FixtureType,
         rooms::{NpcPosition, NpcPositionDescriptor},
-        LifeModifier, NonPlayer, Species,
+        Effects, Health, LifeModifier, NonPlayer, Rank, Species,
     },
     generators::{
-        generator::Generator, non_players::NonPlayerGeneratorBuilder, CharacterGeneratorBuilder,
+        content_pack::ContentPack, generator::Generator, items::enchant, name::generate_npc_title,
+        non_players::NonPlayerGeneratorBuilder, CharacterGeneratorBuilder,
         InventoryGeneratorBuilder,
     },
     utils::rolls::{roll_d100, roll_percent_succeeds},
@@ -16,7 +20,9 @@ use crate::{
 
 use super::BuildNpcsArgs;
 
-const SPAWN_FROM_GHOST_CHANCE: i32 = 10;
+const ELITE_CHANCE: i32 = 20;
+const BOSS_CHANCE: i32 = 10;
+const HEALTH_PER_SPARE_ATTRIBUTE: i32 = 2;
 
 pub fn build_npc_positions(
     fixtures_in_room: Vec<FixtureType>,
@@ -32,30 +38,54 @@ pub fn build_npc_positions(
     }
 
     let mut ghost_spawned = false;
+    let mut boss_spawned = false;
 
     (0..num_groups)
-        .flat_map(|_| {
+        .flat_map(|group| {
             // For each group, find a starting race.
             let starter_species = choose_species(&args.possible_species);
             // Get the group size based on the species.
-            let group_size = group_size(&starter_species);
-            let life_modifier = life_modifier(&starter_species, &args.possible_life_modifiers);
+            let group_size = group_size(&starter_species, &args.content_pack);
+            let life_modifier = life_modifier(
+                &starter_species,
+                &args.possible_life_modifiers,
+                args.undead_chance,
+            );
             let mut species = starter_species;
-            let mut prototype = npc_prototype(&species, life_modifier, danger_level);
+            let mut prototype =
+                npc_prototype(&species, life_modifier, danger_level, &args.content_pack);
+            let leader_rank = rank(&mut rng, danger_level, args, group == 0, boss_spawned);
+            boss_spawned |= leader_rank == Some(Rank::Boss);
 
             let mut npc_positions: Vec<NpcPosition> = Vec::new();
             (0..group_size).for_each(|index| {
-                if roll_percent_succeeds(&mut rng, SPAWN_FROM_GHOST_CHANCE)
+                let rank = if index == 0 { leader_rank } else { None };
+                if rank.is_none()
+                    && roll_percent_succeeds(&mut rng, args.spawn_from_ghost_chance)
                     && !args.ghosts.is_empty()
                     && !ghost_spawned
                 {
                     let index = rng.gen_range(0..args.ghosts.len());
                     let ghost = args.ghosts.get(index).unwrap();
 
+                    // The ghost comes back whole, whatever state its player died in,
+                    // and with its own copy of the gear so it can haunt more than one room.
+                    let mut character = ghost.character.clone();
+                    character.heal_to_max();
+                    character.current_effects = Effects::default();
+                    for character_item in character.inventory.equipment.iter_mut() {
+                        character_item.item.id = Uuid::new_v4();
+                    }
+
                     let npc = NonPlayer {
-                        character: ghost.character.clone(),
+                        character,
                         id: Uuid::new_v4(),
                         name: ghost.name.clone(),
+                        rank: None,
+                        title: ghost
+                            .killer_name
+                            .as_ref()
+                            .map(|killer_name| format!("slain by {}", killer_name)),
                     };
 
                     let position_descriptor = position_descriptor(&fixtures_in_room, false);
@@ -67,12 +97,21 @@ pub fn build_npc_positions(
                     ghost_spawned = true;
                 } else {
                     if index > 0 {
-                        species = switch_species(&species);
-                        prototype = npc_prototype(&species, life_modifier, danger_level);
+                        species = switch_species(&species, args.keep_species_chance);
+                        prototype = npc_prototype(
+                            &species,
+                            life_modifier,
+                            danger_level,
+                            &args.content_pack,
+                        );
                     }
                     let mut npc = prototype.generate();
+                    if let Some(rank) = rank {
+                        promote(&mut npc, rank);
+                    }
 
                     let include_dead_spawn_positions = args.allow_npcs_to_spawn_dead
+                        && rank.is_none()
                         && !matches!(&npc.character.species, Species::Phantom | Species::Shadow)
                         && npc.character.life_modifier.is_none();
 
@@ -95,11 +134,72 @@ pub fn build_npc_positions(
         .collect()
 }
 
-const KEEP_SPECIES_CHANCE: i32 = 90;
+/// Group leaders can be elites once the danger is high enough, and one group in
+/// a room can be led by a boss. The first group in a lair always has a leader.
+fn rank(
+    rng: &mut ThreadRng,
+    danger_level: u32,
+    args: &BuildNpcsArgs,
+    is_first_group: bool,
+    boss_spawned: bool,
+) -> Option<Rank> {
+    let in_lair = args.is_lair && is_first_group;
+
+    if !boss_spawned
+        && danger_level >= args.boss_danger_level
+        && (in_lair || roll_percent_succeeds(rng, BOSS_CHANCE))
+    {
+        Some(Rank::Boss)
+    } else if in_lair
+        || (danger_level >= args.elite_danger_level && roll_percent_succeeds(rng, ELITE_CHANCE))
+    {
+        Some(Rank::Elite)
+    } else {
+        None
+    }
+}
+
+fn promote(npc: &mut NonPlayer, rank: Rank) {
+    npc.rank = Some(rank);
+    npc.title = Some(generate_npc_title(&rank));
+
+    // Attributes can't go past the maximum, so whatever doesn't fit toughens
+    // them up instead.
+    let stats = &mut npc.character.stats;
+    let mut spare = 0;
+    for attribute in [
+        &mut stats.attributes.might,
+        &mut stats.attributes.agility,
+        &mut stats.attributes.wits,
+        &mut stats.attributes.resolve,
+    ] {
+        let boosted = *attribute + rank.attribute_bonus();
+        *attribute = boosted.min(MAX_ATTRIBUTE);
+        spare += boosted - *attribute;
+    }
+    stats.health = Health::from_max(
+        stats.health.max * rank.health_multiplier() + spare * HEALTH_PER_SPARE_ATTRIBUTE,
+    );
+
+    // Readied items come first, so the enchantments show up in a fight, but
+    // anything else they carry will do when nothing is readied.
+    let mut num_enchanted = 0;
+    for at_the_ready in [true, false] {
+        for character_item in npc.character.inventory.equipment.iter_mut() {
+            if num_enchanted >= rank.num_enchanted_items() {
+                return;
+            }
+
+            if character_item.at_the_ready == at_the_ready && enchant(&mut character_item.item) {
+                num_enchanted += 1;
+            }
+        }
+    }
+}
 
-fn switch_species(species: &Species) -> Species {
+fn switch_species(species: &Species, keep_species_chance: i32) -> Species {
     let mut rng = rand::thread_rng();
-    if roll_percent_succeeds(&mut rng, KEEP_SPECIES_CHANCE) {
+    if roll_percent_succeeds(&mut rng, keep_species_chance) {
         return *species;
     }
 
@@ -133,15 +233,9 @@ fn choose_species(species: &[Species]) -> Species {
     species.get(index).cloned().unwrap_or(Species::Shadow)
 }
 
-fn group_size(species: &Species) -> usize {
-    let range = match *species {
-        Species::Bugbear | Species::Hobgoblin => 1..=2,
-        Species::Goblin | Species::Kobold => 1..=3,
-        _ => 1..=1,
-    };
-
+fn group_size(species: &Species, content_pack: &ContentPack) -> usize {
     let mut rng = rand::thread_rng();
-    rng.gen_range(range)
+    rng.gen_range(content_pack.group_size(species))
 }
 
 fn position_descriptor(
@@ -239,15 +333,17 @@ fn weapon_rack_positions() -> Vec<NpcPositionDescriptor> {
     vec![NpcPositionDescriptor::IsLookingAtTheWeaponRack]
 }
 
-const UNDEAD_CHANCE: i32 = 15;
-
-fn life_modifier(species: &Species, possible_modifiers: &[LifeModifier]) -> Option<LifeModifier> {
+fn life_modifier(
+    species: &Species,
+    possible_modifiers: &[LifeModifier],
+    undead_chance: i32,
+) -> Option<LifeModifier> {
     let mut rng = rand::thread_rng();
     if matches!(species, &Species::Phantom | &Species::Shadow) {
         return None;
     }
 
-    if roll_percent_succeeds(&mut rng, UNDEAD_CHANCE) {
+    if roll_percent_succeeds(&mut rng, undead_chance) {
         let type_roll = roll_d100(&mut rng, 1, 0);
         if (0..=33).contains(&type_roll) && possible_modifiers.contains(&LifeModifier::Skeleton) {
             Some(LifeModifier::Skeleton)
@@ -269,6 +365,7 @@ fn npc_prototype(
     species: &Species,
     life_modifier: Option<LifeModifier>,
     danger_level: u32,
+    content_pack: &Arc<ContentPack>,
 ) -> impl Generator<NonPlayer> {
     let num_equipped_weapons = if (1..=10).contains(&danger_level) {
         1..=1
@@ -292,12 +389,14 @@ fn npc_prototype(
         .danger_level(danger_level)
         .num_equipped_weapons(num_equipped_weapons)
         .num_equipped_wearables(num_equipped_wearables)
+        .content_pack(content_pack.clone())
         .to_owned();
 
     let mut character_gen_builder = CharacterGeneratorBuilder::new()
         .danger_level(danger_level)
         .inventory_generator_builder(inventory_generator)
         .species(*species)
+        .content_pack(content_pack.clone())
         .to_owned();
 
     if let Some(modifier) = life_modifier {
@@ -307,7 +406,63 @@ fn npc_prototype(
     let npc_gen_builder = NonPlayerGeneratorBuilder::default()
         .danger_level(danger_level)
         .character_gen_builder(character_gen_builder)
+        .content_pack(content_pack.clone())
         .to_owned();
 
     npc_gen_builder.build()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        components::{
+            attributes::MAX_ATTRIBUTE,
+            items::{CharacterItem, ItemType, LocationTag},
+            Rank,
+        },
+        generators::{
+            generator::Generator, items::item_generator_for_level,
+            non_players::NonPlayerGeneratorBuilder,
+        },
+    };
+
+    use super::{promote, HEALTH_PER_SPARE_ATTRIBUTE};
+
+    #[test]
+    fn an_elite_with_nothing_readied_still_gets_an_enchanted_item() {
+        let mut npc = NonPlayerGeneratorBuilder::new().build().generate();
+        npc.character.inventory.equipment = vec![CharacterItem {
+            item: item_generator_for_level(&ItemType::LongSword, false, 1, &Arc::default())
+                .generate(),
+            equipped_location: LocationTag::Packed,
+            at_the_ready: false,
+        }];
+
+        promote(&mut npc, Rank::Elite);
+
+        assert!(!npc.character.inventory.equipment[0].item.affixes.is_empty());
+    }
+
+    #[test]
+    fn a_boss_never_goes_past_the_max_attribute_and_gets_tougher_instead() {
+        let mut npc = NonPlayerGeneratorBuilder::new().build().generate();
+        npc.character.stats.attributes.might = MAX_ATTRIBUTE - 1;
+        npc.character.stats.attributes.agility = MAX_ATTRIBUTE - 1;
+        npc.character.stats.attributes.wits = MAX_ATTRIBUTE - 1;
+        npc.character.stats.attributes.resolve = MAX_ATTRIBUTE - 1;
+        let max_health = npc.character.stats.health.max;
+
+        promote(&mut npc, Rank::Boss);
+
+        let attributes = &npc.character.stats.attributes;
+        assert_eq!(MAX_ATTRIBUTE, attributes.might);
+        assert_eq!(MAX_ATTRIBUTE, attributes.resolve);
+        let spare = 4 * (Rank::Boss.attribute_bonus() - 1);
+        assert_eq!(
+            max_health * Rank::Boss.health_multiplier() + spare * HEALTH_PER_SPARE_ATTRIBUTE,
+            npc.character.stats.health.max
+        );
+    }
+}
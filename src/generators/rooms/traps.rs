@@ -0,0 +1,76 @@
+use rand::{prelude::ThreadRng, Rng};
+use uuid::Uuid;
+
+use crate::{
+    components::{fixtures::FixtureType, rooms::Room, Poison, Trap, TrapType},
+    utils::rolls::roll_percent_succeeds,
+};
+
+const PRESSURE_PLATE_CHANCE: i32 = 10;
+const NEEDLE_TRAP_CHANCE: i32 = 20;
+const TRIPWIRE_CHANCE: i32 = 10;
+const POISONED_PLATE_CHANCE: i32 = 30;
+const BASE_TRAP_DIFFICULTY: i32 = 7;
+const MAX_TRAP_DIFFICULTY: i32 = 15;
+const DANGER_PER_DIFFICULTY: u32 = 10;
+const DANGER_PER_DAMAGE_ROLL: u32 = 20;
+
+/// Sets traps in a freshly generated room. The entrance is never trapped,
+/// since the player has to come through it to get in.
+pub fn add_traps(room: &mut Room, entrance_id: Option<Uuid>, danger_level: u32) {
+    let mut rng = rand::thread_rng();
+
+    if roll_percent_succeeds(&mut rng, PRESSURE_PLATE_CHANCE) {
+        let poisoned = roll_percent_succeeds(&mut rng, POISONED_PLATE_CHANCE);
+        room.traps.push(trap(
+            &mut rng,
+            TrapType::PressurePlate,
+            danger_level,
+            poisoned,
+        ));
+    }
+
+    for exit in room
+        .exits
+        .iter_mut()
+        .filter(|exit| Some(exit.id) != entrance_id && exit.exit_type.can_be_used())
+    {
+        if roll_percent_succeeds(&mut rng, TRIPWIRE_CHANCE) {
+            exit.trap = Some(trap(&mut rng, TrapType::Tripwire, danger_level, false));
+        }
+    }
+
+    for position in room.fixture_positions.iter_mut().filter(|position| {
+        matches!(
+            position.fixture.fixture_type,
+            FixtureType::Chest | FixtureType::Coffin
+        )
+    }) {
+        if roll_percent_succeeds(&mut rng, NEEDLE_TRAP_CHANCE) {
+            position.fixture.trap = Some(trap(&mut rng, TrapType::Needle, danger_level, true));
+        }
+    }
+}
+
+fn trap(rng: &mut ThreadRng, trap_type: TrapType, danger_level: u32, poisoned: bool) -> Trap {
+    let difficulty = (BASE_TRAP_DIFFICULTY + (danger_level / DANGER_PER_DIFFICULTY) as i32)
+        .min(MAX_TRAP_DIFFICULTY);
+    let damage_rolls = 1 + (danger_level / DANGER_PER_DAMAGE_ROLL) as usize;
+    let poison = if poisoned {
+        Some(Poison {
+            damage: rng.gen_range(1..=2),
+            duration: rng.gen_range(2..=4),
+        })
+    } else {
+        None
+    };
+
+    Trap {
+        id: Uuid::new_v4(),
+        trap_type,
+        armed: true,
+        damage_rolls,
+        poison,
+        difficulty,
+    }
+}
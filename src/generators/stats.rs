@@ -1,11 +1,17 @@
-use rand::Rng;
+use std::ops::RangeInclusive;
+
+use rand::{prelude::ThreadRng, Rng};
 
 use crate::{
-    components::{damage::AttackEffect, Attack, Defense, Health, Size, Species, Stats},
+    components::{
+        attributes::{MAX_ATTRIBUTE, MIN_ATTRIBUTE},
+        damage::AttackEffect,
+        Attack, Attributes, Defense, Health, Size, Species, Stats,
+    },
     utils::rolls::{roll_d6, roll_percent_succeeds},
 };
 
-use super::generator::Generator;
+use super::{generator::Generator, ContentPack};
 
 pub fn build_specific_health(
     max_health: i32,
@@ -21,12 +27,12 @@ pub fn build_specific_health(
     }
 }
 
-pub fn build_default_health_rolls(species: &Species, use_species_base: bool) -> StatsPrototype {
-    let num_health_rolls = match *species {
-        Species::Ogre => 5,
-        Species::Dragonkin | Species::Phantom | Species::Rockoblin | Species::Shadow => 4,
-        _ => 3,
-    };
+pub fn build_default_health_rolls(
+    species: &Species,
+    use_species_base: bool,
+    content_pack: &ContentPack,
+) -> StatsPrototype {
+    let num_health_rolls = content_pack.health_rolls(species);
 
     StatsPrototype {
         max_health: None,
@@ -41,12 +47,9 @@ pub fn build_default_health_rolls_for_danger_level(
     species: &Species,
     danger_level: u32,
     use_species_base: bool,
+    content_pack: &ContentPack,
 ) -> StatsPrototype {
-    let num_health_rolls = match *species {
-        Species::Ogre => 5,
-        Species::Dragonkin | Species::Phantom | Species::Rockoblin | Species::Shadow => 4,
-        _ => 3,
-    };
+    let num_health_rolls = content_pack.health_rolls(species);
 
     StatsPrototype {
         max_health: None,
@@ -66,6 +69,7 @@ pub struct StatsPrototype {
 }
 
 const NON_AVERAGE_HEIGHT_CHANCE: i32 = 40;
+const ATTRIBUTE_VARIANCE: RangeInclusive<i32> = -1..=1;
 
 fn non_average_heights() -> Vec<Size> {
     vec![
@@ -140,11 +144,26 @@ impl Generator<Stats> for StatsPrototype {
             height,
             base_attack: self.base_attack(),
             base_damage_resistance: self.base_damage_resistance(),
+            attributes: self.attributes(&mut rng),
         }
     }
 }
 
 impl StatsPrototype {
+    fn attributes(&self, rng: &mut ThreadRng) -> Attributes {
+        let base = species_attributes(&self.species);
+        let mut vary = |value: i32| -> i32 {
+            (value + rng.gen_range(ATTRIBUTE_VARIANCE)).clamp(MIN_ATTRIBUTE, MAX_ATTRIBUTE)
+        };
+
+        Attributes {
+            might: vary(base.might),
+            agility: vary(base.agility),
+            wits: vary(base.wits),
+            resolve: vary(base.resolve),
+        }
+    }
+
     fn base_attack(&self) -> Option<Attack> {
         if !self.use_species_base {
             return None;
@@ -207,3 +226,29 @@ impl StatsPrototype {
         }
     }
 }
+
+fn species_attributes(species: &Species) -> Attributes {
+    let (might, agility, wits, resolve) = match *species {
+        Species::Bugbear => (7, 5, 4, 5),
+        Species::Dragonkin => (7, 4, 5, 6),
+        Species::Frogkin => (4, 7, 4, 4),
+        Species::Goblin => (3, 7, 6, 4),
+        Species::Hobgoblin => (5, 5, 6, 5),
+        Species::Kobold => (3, 6, 6, 4),
+        Species::Lizardkin => (5, 6, 4, 6),
+        Species::Moblin => (5, 5, 4, 5),
+        Species::Ogre => (9, 2, 2, 6),
+        Species::Orc => (7, 4, 4, 6),
+        Species::Phantom => (3, 8, 6, 7),
+        Species::Rockoblin => (7, 2, 4, 8),
+        Species::Shadow => (3, 8, 7, 6),
+        Species::Turtlekin => (5, 2, 6, 8),
+    };
+
+    Attributes {
+        might,
+        agility,
+        wits,
+        resolve,
+    }
+}
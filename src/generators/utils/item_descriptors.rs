@@ -1,17 +1,19 @@
-use strum::IntoEnumIterator;
-
-use crate::components::{
-    items::{Descriptor, ItemType},
-    Material, Tag, Tagged,
+use crate::{
+    components::{
+        items::{Descriptor, ItemType},
+        Material, Tag, Tagged,
+    },
+    generators::ContentPack,
 };
 
-pub fn valid_for_level(descriptor: &Descriptor, level: u32) -> bool {
+/// Lowest and highest item levels a descriptor can show up on in the default content.
+pub fn default_level_range(descriptor: &Descriptor) -> (Option<u32>, Option<u32>) {
     match descriptor {
         Descriptor::Beaten
         | Descriptor::Broken
         | Descriptor::Cracked
         | Descriptor::Chipped
-        | Descriptor::Crumbling => level <= 5,
+        | Descriptor::Crumbling => (None, Some(5)),
         Descriptor::Dingy
         | Descriptor::Dull
         | Descriptor::Moldy
@@ -24,32 +26,26 @@ pub fn valid_for_level(descriptor: &Descriptor, level: u32) -> bool {
         | Descriptor::Stained
         | Descriptor::Tangled
         | Descriptor::WaterLogged
-        | Descriptor::Torn => level <= 10,
+        | Descriptor::Torn => (None, Some(10)),
         Descriptor::Heavy | Descriptor::Keen | Descriptor::Shimmering | Descriptor::Shiny => {
-            level > 10
+            (Some(11), None)
         }
-        Descriptor::Tarnished => level <= 15,
-        Descriptor::Bleached | Descriptor::Drab => level <= 20,
-        Descriptor::Quality => level > 20,
-        Descriptor::Dirty => level <= 50,
+        Descriptor::Tarnished => (None, Some(15)),
+        Descriptor::Bleached | Descriptor::Drab => (None, Some(20)),
+        Descriptor::Quality => (Some(21), None),
+        Descriptor::Dirty => (None, Some(50)),
         Descriptor::Bloodstained
         | Descriptor::Colourful
         | Descriptor::Smoothed
-        | Descriptor::Weathered => true,
+        | Descriptor::Weathered => (None, None),
     }
 }
 
-pub fn matches_tags(tags: &[Tag]) -> Vec<Descriptor> {
-    Descriptor::iter()
-        .filter(|descriptor| {
-            tags_for_descriptor(descriptor)
-                .iter()
-                .any(|tag| tags.contains(tag))
-        })
-        .collect()
+pub fn matches_tags(tags: &[Tag], content_pack: &ContentPack) -> Vec<Descriptor> {
+    content_pack.descriptors_matching_tags(tags)
 }
 
-fn tags_for_descriptor(descriptor: &Descriptor) -> Vec<Tag> {
+pub fn default_tags(descriptor: &Descriptor) -> Vec<Tag> {
     match *descriptor {
         Descriptor::Beaten => vec![Tag::Wood, Tag::Bone, Tag::Leather],
         Descriptor::Bleached => vec![Tag::Bone, Tag::Wood],
@@ -117,6 +113,7 @@ pub fn possible_descriptors(
     item_type: &ItemType,
     material: &Option<Material>,
     level: u32,
+    content_pack: &ContentPack,
 ) -> Vec<Descriptor> {
     match material {
         Some(material) => {
@@ -125,11 +122,11 @@ pub fn possible_descriptors(
                 .into_iter()
                 .chain(material.tags().into_iter())
                 .collect();
-            matches_tags(&tags)
+            content_pack.descriptors_matching_tags(&tags)
         }
-        None => matches_tags(&item_type.tags()),
+        None => content_pack.descriptors_matching_tags(&item_type.tags()),
     }
     .into_iter()
-    .filter(|descriptor| valid_for_level(descriptor, level))
+    .filter(|descriptor| content_pack.descriptor_valid_for_level(descriptor, level))
     .collect()
 }
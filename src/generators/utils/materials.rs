@@ -1,9 +1,16 @@
 use crate::components::{items::ItemType, Material};
 
-pub fn possible_materials(item_type: &ItemType) -> Vec<Material> {
+/// The materials each item type can be made of in the default content.
+pub fn default_materials(item_type: &ItemType) -> Vec<Material> {
     match *item_type {
         ItemType::Breastplate => vec![Material::Iron, Material::Leather, Material::Steel],
         ItemType::Mask => vec![Material::Bone, Material::Iron],
+        ItemType::Key => vec![
+            Material::Bone,
+            Material::Gold,
+            Material::Iron,
+            Material::Steel,
+        ],
         ItemType::Cloak => {
             vec![Material::Linen, Material::Hide, Material::Wool]
         }
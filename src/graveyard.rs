@@ -0,0 +1,123 @@
+#[cfg(feature = "json")]
+use std::{fs, io::ErrorKind, path::Path};
+
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "json")]
+use crate::errors::Error;
+use crate::{
+    components::{games::GameState, Ghost},
+    events::Event,
+};
+
+const MAX_GHOSTS: usize = 100;
+
+/// The ghosts of players from earlier games. A game's `ghosts` aren't saved
+/// with it, so the graveyard is what carries them from one game to the next.
+/// Bury the ghosts a game's events produce, save the graveyard, and haunt every
+/// new or reloaded game with it so the ghosts can turn up in its rooms.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct Graveyard {
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub ghosts: Vec<Ghost>,
+}
+
+impl Graveyard {
+    /// Reads the graveyard from a file, starting an empty one if there is no file yet.
+    #[cfg(feature = "json")]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                serde_json::from_str(&text).map_err(|e| Error::GraveyardError(e.to_string()))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::GraveyardError(e.to_string())),
+        }
+    }
+
+    /// Writes the graveyard to a file, replacing whatever was there before.
+    #[cfg(feature = "json")]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let text = serde_json::to_string(self).map_err(|e| Error::GraveyardError(e.to_string()))?;
+        fs::write(path, text).map_err(|e| Error::GraveyardError(e.to_string()))
+    }
+
+    /// Keeps the ghost of every player that died in the events. Only the most
+    /// recent ghosts are kept once the graveyard is full.
+    pub fn bury(&mut self, events: &[Event]) {
+        for event in events.iter() {
+            if let Event::GhostEscapesToTheVoid(ghost_escapes) = event {
+                self.ghosts.push(Ghost {
+                    character: ghost_escapes.character.clone(),
+                    name: ghost_escapes.name.clone(),
+                    killer_name: ghost_escapes.killer_name.clone(),
+                });
+            }
+        }
+
+        if self.ghosts.len() > MAX_GHOSTS {
+            self.ghosts.drain(..self.ghosts.len() - MAX_GHOSTS);
+        }
+    }
+
+    /// Lets the ghosts be generated as NPCs in the rooms of the game from now on.
+    pub fn haunt(&self, state: &mut GameState) {
+        state.ghosts = self.ghosts.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::{Action, SearchRoom},
+        components::{LifeModifier, Poison},
+        generators::{game::game_generator, generator::Generator, players::player_generator},
+        handlers::handle_action,
+    };
+
+    use super::Graveyard;
+
+    #[test]
+    fn ghosts_of_dead_players_haunt_new_games() {
+        let mut state = game_generator(None, None, None).generate();
+        state.current_room_mut().npc_positions.clear();
+        state.current_room_mut().descriptors.clear();
+        let mut player = player_generator(Some("Gus".to_string()), None, None).generate();
+        player.character.stats.health.current = 1;
+        player.character.current_effects.poison = Some(Poison {
+            damage: 5,
+            duration: 3,
+        });
+
+        let handled = handle_action(&Action::SearchRoom(SearchRoom {}), &state, &player).unwrap();
+        assert!(handled.new_player.character.is_dead());
+
+        let mut graveyard = Graveyard::default();
+        graveyard.bury(&handled.events);
+
+        #[cfg(feature = "json")]
+        let graveyard = {
+            let path = std::env::temp_dir().join(format!("graveyard-{}.json", player.id));
+            graveyard.save(&path).unwrap();
+            let loaded = Graveyard::load(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            loaded
+        };
+
+        let mut state = game_generator(None, None, None).generate();
+        graveyard.haunt(&mut state);
+
+        assert_eq!(1, state.ghosts.len());
+        assert_eq!(Some("Gus".to_string()), state.ghosts[0].name);
+        assert_eq!(
+            Some(LifeModifier::Ghost),
+            state.ghosts[0].character.life_modifier
+        );
+        assert_eq!(
+            player.character.inventory.equipment.len(),
+            state.ghosts[0].character.inventory.equipment.len()
+        );
+    }
+}
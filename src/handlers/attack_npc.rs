@@ -1,21 +1,23 @@
-use std::ops::RangeInclusive;
-
 use rand::Rng;
 
 use crate::{
     actions::AttackNpc,
-    components::{damage::AttackEffect, games::GameState, PlayerCharacter, Species},
+    components::{
+        damage::AttackEffect, games::GameState, items::Affix, NonPlayer, PlayerCharacter, Species,
+    },
     errors::Error,
-    events::{DeadNpcBeaten, Event, NpcItemDestroyed, NpcMissed, NpcPoisoned},
-    utils::{ids::parse_id, rolls::roll_percent_succeeds},
+    events::{DeadNpcBeaten, Event, NpcMissed, NpcMissedInTheDark, NpcPoisoned, PlayerHealed},
+    utils::{
+        ids::parse_id,
+        rolls::{roll_percent_succeeds, roll_range},
+    },
 };
 
-use super::helpers::damage_npc;
-
-const TOXIC_RANGE: RangeInclusive<i32> = 3..=6;
-const TOXIC_DURATION_RANGE: RangeInclusive<i32> = 2..=4;
+use super::helpers::{
+    damage_npc, misses_in_the_dark, npc_item_hit_with_acid, npc_items_worn, player_items_worn,
+};
 
-const ACID_DESTROYS_ITEM_CHANCE: i32 = 25;
+const LIFE_STEAL_DIVISOR: i32 = 4;
 
 pub fn handle(
     attack_npc: &AttackNpc,
@@ -38,7 +40,12 @@ pub fn handle(
             attacker_id: player.id,
             npc_id,
         }));
-    } else if npc_will_dodge(&npc.character.species) {
+    } else if misses_in_the_dark(room) {
+        events.push(Event::NpcMissedInTheDark(NpcMissedInTheDark {
+            attacker_id: player.id,
+            npc_id,
+        }));
+    } else if npc_will_dodge(npc) {
         events.push(Event::NpcMissed(NpcMissed {
             attacker_id: player.id,
             npc_id,
@@ -51,6 +58,18 @@ pub fn handle(
         let damage = calculated_damage.min(npc.character.get_current_health());
         let (mut damage_events, npc_dead) = damage_npc(player, npc, damage);
 
+        events.append(&mut player_items_worn(
+            &player.character.inventory.readied_weapons(),
+            &mut rng,
+        ));
+        if npc_defense.damage_resistance > 0 {
+            events.append(&mut npc_items_worn(
+                npc.id,
+                &npc.character.inventory.readied_defensive_items(),
+                &mut rng,
+            ));
+        }
+
         // If npc is alive, handle any attack effects on player weapons
         if !npc_dead {
             for effect in player_attack.effects.iter() {
@@ -58,20 +77,20 @@ pub fn handle(
                     AttackEffect::Toxic => {
                         events.push(Event::NpcPoisoned(NpcPoisoned {
                             npc_id: npc.id,
-                            damage: rng.gen_range(TOXIC_RANGE),
-                            duration: rng.gen_range(TOXIC_DURATION_RANGE),
+                            damage: roll_range(&mut rng, &state.ruleset.toxic_damage),
+                            duration: roll_range(&mut rng, &state.ruleset.toxic_duration),
                         }));
                     }
                     AttackEffect::Acidic => {
-                        if roll_percent_succeeds(&mut rng, ACID_DESTROYS_ITEM_CHANCE) {
+                        if roll_percent_succeeds(
+                            &mut rng,
+                            state.ruleset.player_acid_destroys_item_chance,
+                        ) {
                             let equipped_items = npc.character.inventory.readied_weapons();
                             let index = rng.gen_range(0..equipped_items.len());
                             if let Some(character_item) = equipped_items.get(index) {
                                 events.push(Event::NpcHitWithAcid(npc.id));
-                                events.push(Event::NpcItemDestroyed(NpcItemDestroyed {
-                                    npc_id: npc.id,
-                                    item_id: character_item.item.id,
-                                }))
+                                events.append(&mut npc_item_hit_with_acid(npc.id, character_item));
                             }
                         }
                     }
@@ -81,6 +100,15 @@ pub fn handle(
         }
 
         events.append(&mut damage_events);
+
+        if damage > 0 && player.character.has_readied_affix(&Affix::LifeStealing) {
+            let missing_health =
+                player.character.stats.health.max - player.character.get_current_health();
+            let damage_healed = (damage / LIFE_STEAL_DIVISOR).max(1).min(missing_health);
+            if damage_healed > 0 {
+                events.push(Event::PlayerHealed(PlayerHealed { damage_healed }));
+            }
+        }
     }
 
     Ok(events)
@@ -88,12 +116,29 @@ pub fn handle(
 
 const PHANTOM_DODGE_CHANCE: i32 = 15;
 const SHADOW_DODGE_CHANCE: i32 = 25;
+const DODGE_CHANCE_PER_AGILITY: i32 = 5;
+const ENCUMBERED_DODGE_PERCENT_PENALTY: i32 = 10;
 
-fn npc_will_dodge(species: &Species) -> bool {
+fn npc_will_dodge(npc: &NonPlayer) -> bool {
     let mut rng = rand::thread_rng();
-    match *species {
-        Species::Phantom => roll_percent_succeeds(&mut rng, PHANTOM_DODGE_CHANCE),
-        Species::Shadow => roll_percent_succeeds(&mut rng, SHADOW_DODGE_CHANCE),
-        _ => false,
-    }
+    let character = &npc.character;
+    let species_chance = match character.species {
+        Species::Phantom => PHANTOM_DODGE_CHANCE,
+        Species::Shadow => SHADOW_DODGE_CHANCE,
+        _ => 0,
+    };
+    let agility_chance = character.stats.attributes.agility_modifier() * DODGE_CHANCE_PER_AGILITY;
+
+    let encumbered_penalty = if character.is_encumbered() {
+        ENCUMBERED_DODGE_PERCENT_PENALTY
+    } else {
+        0
+    };
+
+    let rank_chance = npc.rank.map_or(0, |rank| rank.dodge_chance());
+
+    roll_percent_succeeds(
+        &mut rng,
+        species_chance + agility_chance + rank_chance - encumbered_penalty,
+    )
 }
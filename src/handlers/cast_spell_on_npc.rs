@@ -1,5 +1,3 @@
-use std::ops::RangeInclusive;
-
 use rand::Rng;
 
 use crate::{
@@ -7,21 +5,16 @@ use crate::{
     components::{games::GameState, spells::SpellName, PlayerCharacter},
     errors::Error,
     events::{
-        Event, NpcItemDestroyed, NpcPoisonEffectDurationChanged, NpcPoisonLevelChanged,
-        NpcPoisoned, PlayerSpellForgotten, PlayerSpellUsed,
+        Event, NpcPoisonEffectDurationChanged, NpcPoisonLevelChanged, NpcPoisoned,
+        PlayerSpellForgotten, PlayerSpellUsed,
+    },
+    utils::{
+        ids::parse_id,
+        rolls::{roll_percent_succeeds, roll_range},
     },
-    utils::{ids::parse_id, rolls::roll_percent_succeeds},
 };
 
-use super::helpers::damage_npc;
-
-const POISON_DART_DAMAGE_RANGE: RangeInclusive<i32> = 2..=6;
-const POISON_DART_DURATION_RANGE: RangeInclusive<i32> = 1..=4;
-
-const POISON_CLOUD_DAMAGE_RANGE: RangeInclusive<i32> = 1..=8;
-const POISON_CLOUD_DURATION_RANGE: RangeInclusive<i32> = 2..=5;
-
-const ACID_DESTROYS_ITEM_CHANCE: i32 = 75;
+use super::helpers::{damage_npc, npc_item_hit_with_acid};
 
 pub fn handle(
     cast_spell_on_npc: &CastSpellOnNpc,
@@ -41,6 +34,8 @@ pub fn handle(
         None => return Err(Error::NpcNotFoundError(npc_id.to_string())),
     };
 
+    // Spells aren't aimed the way a blade or a flask is, so they find their
+    // target even in a dark room.
     let mut events: Vec<Event> = Vec::new();
 
     events.push(Event::PlayerSpellUsed(PlayerSpellUsed { spell_id }));
@@ -48,7 +43,9 @@ pub fn handle(
     match learned_spell.spell.name {
         SpellName::ElectricBlast => {
             let spell_damage = learned_spell.spell.damage();
-            let damage = spell_damage.min(npc.character.get_current_health());
+            let damage = (spell_damage - npc.character.magic_resistance())
+                .max(1)
+                .min(npc.character.get_current_health());
             let (mut damage_events, _) = damage_npc(player, npc, damage);
             events.append(&mut damage_events);
         }
@@ -58,15 +55,17 @@ pub fn handle(
             } else {
                 learned_spell.spell.damage()
             };
-            let damage = spell_damage.min(npc.character.get_current_health());
+            let damage = (spell_damage - npc.character.magic_resistance())
+                .max(1)
+                .min(npc.character.get_current_health());
             let (mut damage_events, _) = damage_npc(player, npc, damage);
             events.append(&mut damage_events);
         }
         SpellName::PoisonDart => {
             if npc.character.current_effects.poison.is_none() {
                 let mut rng = rand::thread_rng();
-                let damage = rng.gen_range(POISON_CLOUD_DAMAGE_RANGE);
-                let duration = rng.gen_range(POISON_CLOUD_DURATION_RANGE);
+                let damage = roll_range(&mut rng, &state.ruleset.poison_dart_damage);
+                let duration = roll_range(&mut rng, &state.ruleset.poison_dart_duration);
 
                 events.push(Event::NpcPoisoned(NpcPoisoned {
                     npc_id,
@@ -75,8 +74,8 @@ pub fn handle(
                 }));
             } else {
                 let mut rng = rand::thread_rng();
-                let damage = rng.gen_range(POISON_CLOUD_DAMAGE_RANGE);
-                let duration = rng.gen_range(POISON_CLOUD_DURATION_RANGE);
+                let damage = roll_range(&mut rng, &state.ruleset.poison_dart_damage);
+                let duration = roll_range(&mut rng, &state.ruleset.poison_dart_duration);
 
                 events.push(Event::NpcPoisonLevelChanged(NpcPoisonLevelChanged {
                     npc_id,
@@ -91,8 +90,8 @@ pub fn handle(
             for npc_position in state.current_room().npc_positions.iter() {
                 if npc_position.npc.character.current_effects.poison.is_none() {
                     let mut rng = rand::thread_rng();
-                    let damage = rng.gen_range(POISON_DART_DAMAGE_RANGE);
-                    let duration = rng.gen_range(POISON_DART_DURATION_RANGE);
+                    let damage = roll_range(&mut rng, &state.ruleset.poison_cloud_damage);
+                    let duration = roll_range(&mut rng, &state.ruleset.poison_cloud_duration);
 
                     events.push(Event::NpcPoisoned(NpcPoisoned {
                         npc_id: npc_position.npc.id,
@@ -101,8 +100,8 @@ pub fn handle(
                     }));
                 } else {
                     let mut rng = rand::thread_rng();
-                    let damage = rng.gen_range(POISON_DART_DAMAGE_RANGE);
-                    let duration = rng.gen_range(POISON_DART_DURATION_RANGE);
+                    let damage = roll_range(&mut rng, &state.ruleset.poison_cloud_damage);
+                    let duration = roll_range(&mut rng, &state.ruleset.poison_cloud_duration);
 
                     events.push(Event::NpcPoisonLevelChanged(NpcPoisonLevelChanged {
                         npc_id: npc_position.npc.id,
@@ -119,15 +118,12 @@ pub fn handle(
         }
         SpellName::AcidSplash => {
             let mut rng = rand::thread_rng();
-            if roll_percent_succeeds(&mut rng, ACID_DESTROYS_ITEM_CHANCE) {
+            if roll_percent_succeeds(&mut rng, state.ruleset.acid_splash_destroys_item_chance) {
                 let equipped_items = npc.character.inventory.readied_weapons();
                 let index = rng.gen_range(0..equipped_items.len());
                 if let Some(character_item) = equipped_items.get(index) {
                     events.push(Event::NpcHitWithAcid(npc_id));
-                    events.push(Event::NpcItemDestroyed(NpcItemDestroyed {
-                        npc_id,
-                        item_id: character_item.item.id,
-                    }));
+                    events.append(&mut npc_item_hit_with_acid(npc_id, character_item));
                 }
             }
         }
@@ -3,6 +3,7 @@ use rand::Rng;
 use crate::{
     actions::CastSpellOnPlayer,
     components::{
+        games::GameState,
         spells::SpellName,
         PlayerCharacter, {Attack, Defense},
     },
@@ -14,10 +15,11 @@ use crate::{
     utils::{ids::parse_id, rolls::roll_percent_succeeds},
 };
 
-const ACID_DESTROYS_ITEM_CHANCE: i32 = 75;
+use super::helpers::player_item_hit_with_acid;
 
 pub fn handle(
     cast_spell_on_player: &CastSpellOnPlayer,
+    state: &GameState,
     player: &PlayerCharacter,
 ) -> Result<Vec<Event>, Error> {
     let spell_id = parse_id(&cast_spell_on_player.spell_id)?;
@@ -32,7 +34,8 @@ pub fn handle(
 
     match learned_spell.spell.name {
         SpellName::ElectricBlast | SpellName::RagingFireball => {
-            let damage = learned_spell.spell.damage();
+            let damage =
+                (learned_spell.spell.damage() - player.character.magic_resistance()).max(1);
             events.push(Event::PlayerHit(PlayerHit {
                 attacker_id: player.id,
                 damage,
@@ -79,12 +82,12 @@ pub fn handle(
         }
         SpellName::AcidSplash => {
             let mut rng = rand::thread_rng();
-            if roll_percent_succeeds(&mut rng, ACID_DESTROYS_ITEM_CHANCE) {
+            if roll_percent_succeeds(&mut rng, state.ruleset.acid_splash_destroys_item_chance) {
                 let equipped_items = player.character.inventory.readied_weapons();
                 let index = rng.gen_range(0..equipped_items.len());
                 if let Some(character_item) = equipped_items.get(index) {
                     events.push(Event::PlayerHitWithAcid);
-                    events.push(Event::PlayerItemDestroyed(character_item.item.id));
+                    events.append(&mut player_item_hit_with_acid(character_item));
                 }
             }
         }
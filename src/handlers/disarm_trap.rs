@@ -0,0 +1,27 @@
+use crate::{
+    actions::DisarmTrap,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, TrapDisarmed},
+    utils::ids::parse_id,
+};
+
+use super::helpers::{disarm_trap_succeeds, trigger_trap};
+
+pub fn handle(
+    disarm_trap: &DisarmTrap,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let trap_id = parse_id(&disarm_trap.trap_id)?;
+    let trap = match state.current_room().find_trap(&trap_id) {
+        Some(it) if it.armed && state.knows_trap(&trap_id) => it,
+        _ => return Err(Error::TrapNotFound(trap_id.to_string())),
+    };
+
+    if disarm_trap_succeeds(player, trap) {
+        Ok(vec![Event::TrapDisarmed(TrapDisarmed { trap_id })])
+    } else {
+        Ok(trigger_trap(player, trap))
+    }
+}
@@ -1,13 +1,29 @@
 use crate::{
     actions::ExitRoom,
-    components::games::GameState,
+    components::{games::GameState, rooms::ExitType, PlayerCharacter},
     errors::Error,
-    events::{Event, RoomExited, RoomFirstSeen, RoomGenerated},
-    generators::{generator::Generator, RoomGeneratorBuilder, RoomNpcGenerationArgs},
-    utils::ids::parse_id,
+    events::{
+        BossEncountered, Event, PlayerEscaped, PlayerFell, PlayerKilled, RoomExited, RoomFirstSeen,
+        RoomGenerated,
+    },
+    generators::{
+        generator::Generator,
+        rooms::{assign_exit_directions, way_out},
+        RoomGeneratorBuilder, RoomNpcGenerationArgs,
+    },
+    utils::{ids::parse_id, rolls::roll_d6},
 };
 
-pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Error> {
+use super::helpers::trigger_trap;
+
+const DANGER_PER_DEPTH: u32 = 5;
+const FALL_DAMAGE_ROLLS: usize = 1;
+
+pub fn handle(
+    exit_room: &ExitRoom,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     // We need to check the exit maps for one with the room_id and exit.
     // If there's another exit id then find the room with that exit id and move
     // the player to that room.
@@ -28,12 +44,67 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
         None => return Err(Error::ExitNotFoundError(exit_id.to_string())),
     };
 
+    let current_room = state.current_room();
+    let exit = match current_room.find_exit(&exit_id) {
+        Some(it) => it,
+        None => return Err(Error::ExitNotFoundError(exit_id.to_string())),
+    };
+
+    if !exit.exit_type.can_be_used() {
+        return Err(Error::ExitIsOneWay(exit_id.to_string()));
+    }
+
+    if exit.is_locked() {
+        return Err(Error::ExitIsLocked(exit_id.to_string()));
+    }
+
+    // Walking to the exit sets off its tripwire, or failing that the first armed
+    // pressure plate in the room. Either can stop the player for good.
+    let trap = exit
+        .trap
+        .iter()
+        .chain(current_room.traps.iter())
+        .find(|trap| trap.armed);
+    if let Some(trap) = trap {
+        events.append(&mut trigger_trap(player, trap));
+        if events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerKilled(_)))
+        {
+            return Ok(events);
+        }
+    }
+
+    // There is no room on the other side of the way out, only the end of the game.
+    if exit.exit_type == ExitType::WayOut {
+        events.push(Event::PlayerEscaped(PlayerEscaped { exit_id }));
+        return Ok(events);
+    }
+
     let other_room_id = exit_map.other_room_id(state.current_room_id);
-    let room_id = match other_room_id {
-        Some(id) => id,
+    let (room_id, boss_id) = match other_room_id {
+        Some(id) => (
+            id,
+            state
+                .world
+                .rooms
+                .iter()
+                .find(|room| room.id.eq(&id))
+                .and_then(|room| room.living_boss())
+                .map(|npc| npc.id),
+        ),
         None => {
+            let depth = (current_room.depth as i32 + exit.exit_type.depth_change()).max(0) as u32;
+            let coordinates = match &exit.direction {
+                Some(direction) => current_room.coordinates.neighbour(direction),
+                None => state
+                    .world
+                    .nearest_free_cell(&current_room.coordinates, depth),
+            };
             let room_generator = RoomGeneratorBuilder::new()
-                .danger_level(state.danger_level)
+                .danger_level(state.danger_level + depth * DANGER_PER_DEPTH)
+                .depth(depth)
+                .coordinates(coordinates)
                 .entrance_id(exit_id)
                 .room_npc_generation_args(RoomNpcGenerationArgs {
                     num_groups: None,
@@ -41,18 +112,59 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
                     possible_life_modifiers: None,
                     allow_npcs_to_spawn_dead: None,
                     ghosts: Some(state.ghosts.to_vec()),
+                    undead_chance: None,
+                    elite_danger_level: None,
+                    boss_danger_level: None,
+                    ruleset: Some(state.ruleset.clone()),
                 })
+                .content_pack(state.content_pack.clone())
                 .build();
-            let room = room_generator.generate();
+            let mut room = room_generator.generate();
+            let entrance_direction = exit.direction.map(|direction| direction.opposite());
+            if let Some(entrance) = room.exits.iter_mut().find(|it| it.id.eq(&exit_id)) {
+                *entrance = exit.clone();
+                entrance.exit_type = exit.exit_type.other_side();
+                // The tripwire has just gone off, so it shouldn't be armed again on the way back.
+                if let Some(trap) = entrance.trap.as_mut() {
+                    trap.armed = false;
+                }
+            }
+            if state
+                .victory_conditions
+                .escape_depth
+                .is_some_and(|escape_depth| depth >= escape_depth)
+                && !state.world.has_way_out()
+            {
+                room.exits.push(way_out());
+            }
+            assign_exit_directions(
+                &mut room.exits,
+                entrance_direction.map(|direction| (exit_id, direction)),
+                &state.world.blocked_directions(&coordinates, depth),
+            );
             let room_id = room.id;
+            let boss_id = room.living_boss().map(|npc| npc.id);
             events.push(Event::RoomGenerated(RoomGenerated {
                 room,
                 entrance_id: exit_id,
             }));
-            room_id
+            (room_id, boss_id)
         }
     };
 
+    if exit.exit_type == ExitType::HoleInTheFloor {
+        let mut rng = rand::thread_rng();
+        let agility_modifier = player.character.stats.attributes.agility_modifier();
+        let damage = roll_d6(&mut rng, FALL_DAMAGE_ROLLS, -agility_modifier)
+            .max(1)
+            .min(player.character.get_current_health());
+        events.push(Event::PlayerFell(PlayerFell { damage }));
+
+        if damage >= player.character.get_current_health() {
+            events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
+        }
+    }
+
     events.push(Event::RoomExited(RoomExited {
         exit_id,
         old_room_id: state.current_room_id,
@@ -61,9 +173,121 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
 
     if !state.rooms_seen.contains(&room_id) {
         events.push(Event::RoomFirstSeen(RoomFirstSeen { room_id }));
+        if let Some(npc_id) = boss_id {
+            events.push(Event::BossEncountered(BossEncountered { room_id, npc_id }));
+        }
         events.push(Event::GameDangerLevelIncreased(1));
         events.push(Event::PlayerMaxHealthChanged(1))
     }
 
     Ok(events)
 }
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::ExitRoom,
+        components::{games::GameState, rooms::ExitType, Trap, TrapType},
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{game::game_generator, generator::Generator, players::player_generator},
+    };
+
+    use super::handle;
+
+    fn game_with_exit(exit_type: ExitType) -> (GameState, ExitRoom) {
+        let mut state = game_generator(None, None, None).generate();
+        state.current_room_mut().traps.clear();
+        let exit = state.current_room_mut().exits.first_mut().unwrap();
+        exit.exit_type = exit_type;
+        exit.lock = None;
+        exit.trap = None;
+        let exit_room = ExitRoom {
+            exit_id: exit.id.to_string(),
+        };
+        (state, exit_room)
+    }
+
+    #[test]
+    fn a_tripwire_only_goes_off_once() {
+        let mut state = game_generator(None, None, None).generate();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.health.max = 100;
+        player.character.stats.health.current = 100;
+
+        let trap_id = Uuid::new_v4();
+        let exit = state.current_room_mut().exits.first_mut().unwrap();
+        exit.exit_type = ExitType::Door;
+        exit.lock = None;
+        exit.trap = Some(Trap {
+            id: trap_id,
+            trap_type: TrapType::Tripwire,
+            armed: true,
+            damage_rolls: 1,
+            poison: None,
+            difficulty: 1,
+        });
+        let exit_id = exit.id.to_string();
+        let trap_triggered = |events: &[Event]| {
+            events.iter().any(|event| {
+                matches!(event, Event::TrapTriggered(trap_triggered) if trap_triggered.trap_id == trap_id)
+            })
+        };
+
+        let exit_room = ExitRoom { exit_id };
+        let events = handle(&exit_room, &state, &player).unwrap();
+        assert!(trap_triggered(&events));
+        let (state, player) = apply_events(&events, &state, &player);
+
+        let events = handle(&exit_room, &state, &player).unwrap();
+        assert!(!trap_triggered(&events));
+        let (state, player) = apply_events(&events, &state, &player);
+
+        let events = handle(&exit_room, &state, &player).unwrap();
+        assert!(!trap_triggered(&events));
+    }
+
+    #[test]
+    fn a_hole_in_the_ceiling_cant_be_climbed() {
+        let (state, exit_room) = game_with_exit(ExitType::HoleInTheCeiling);
+        let player = player_generator(None, None, None).generate();
+
+        let result = handle(&exit_room, &state, &player);
+        assert!(matches!(result, Err(Error::ExitIsOneWay(_))));
+    }
+
+    #[test]
+    fn a_hole_in_the_floor_drops_the_player_a_level_deeper() {
+        let (state, exit_room) = game_with_exit(ExitType::HoleInTheFloor);
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.health.max = 100;
+        player.character.stats.health.current = 100;
+
+        let events = handle(&exit_room, &state, &player).unwrap();
+        let depth = events.iter().find_map(|event| match event {
+            Event::RoomGenerated(room_generated) => Some(room_generated.room.depth),
+            _ => None,
+        });
+        assert_eq!(Some(state.current_room().depth + 1), depth);
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerFell(_))));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerKilled(_))));
+    }
+
+    #[test]
+    fn a_fall_can_kill_a_player_on_their_last_legs() {
+        let (state, exit_room) = game_with_exit(ExitType::HoleInTheFloor);
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.health.current = 1;
+
+        let events = handle(&exit_room, &state, &player).unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerKilled(_))));
+    }
+}
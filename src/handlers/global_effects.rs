@@ -1,23 +1,43 @@
 use crate::{
-    components::{games::GameState, PlayerCharacter},
+    components::{games::GameState, items::Affix, rooms::Descriptor, PlayerCharacter},
     events::{
-        Event, NpcDamagedByPoison, NpcPoisonEffectDissipated, NpcPoisonEffectDurationChanged,
-        PlayerKilled, PlayerKilledNpc,
+        Event, NpcDamagedByPoison, NpcHealed, NpcPoisonEffectDissipated,
+        NpcPoisonEffectDurationChanged, PlayerHealed, PlayerKilled, PlayerKilledNpc,
     },
 };
 
+const FREEZING_DAMAGE: i32 = 1;
+
 pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
     let mut events: Vec<Event> = Vec::new();
+    let room = state.current_room();
+
+    // Each effect works on the health the ones before it left, so the player is
+    // only ever killed once.
+    let mut health = player.character.get_current_health();
 
-    if !player.character.is_dead() {
+    if health > 0
+        && room.has_descriptor(&Descriptor::Freezing)
+        && !player.character.has_warm_clothing()
+    {
+        let damage = FREEZING_DAMAGE.min(health);
+        events.push(Event::PlayerDamagedByCold(damage));
+        health -= damage;
+        if health == 0 {
+            events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
+        }
+    }
+
+    if health > 0 {
         if let Some(poison_effect) = &player.character.current_effects.poison {
-            let damage = player
-                .character
-                .get_current_health()
-                .min(poison_effect.damage);
+            let resisted_damage = (poison_effect.damage
+                - player.character.stats.attributes.poison_resistance())
+            .max(1);
+            let damage = health.min(resisted_damage);
             events.push(Event::PlayerDamagedByPoison(damage));
+            health -= damage;
 
-            if damage >= player.character.get_current_health() {
+            if health == 0 {
                 events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
             } else if poison_effect.duration - 1 <= 0 {
                 events.push(Event::PlayerPoisonDissipated);
@@ -27,8 +47,23 @@ pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
         }
     }
 
-    for npc in state
-        .current_room()
+    if health > 0
+        && player.character.has_readied_affix(&Affix::Regenerating)
+        && health < player.character.stats.health.max
+    {
+        events.push(Event::PlayerHealed(PlayerHealed { damage_healed: 1 }));
+        health += 1;
+    }
+
+    if health > 0 && player.character.has_readied_affix(&Affix::Festering) {
+        events.push(Event::PlayerDamagedByPoison(1));
+        health -= 1;
+        if health == 0 {
+            events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
+        }
+    }
+
+    for npc in room
         .npc_positions
         .iter()
         .map(|npc_position| &npc_position.npc)
@@ -37,8 +72,27 @@ pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
             continue;
         }
 
+        if let Some(rank) = &npc.rank {
+            let missing_health =
+                npc.character.stats.health.max - npc.character.get_current_health();
+            let damage_healed = rank.regeneration().min(missing_health);
+            if damage_healed > 0 {
+                events.push(Event::NpcHealed(NpcHealed {
+                    npc_id: npc.id,
+                    damage_healed,
+                }));
+            }
+        }
+
+        // Oil doesn't stick to anything in a damp room.
+        if npc.character.current_effects.covered_in_oil && room.has_descriptor(&Descriptor::Moist) {
+            events.push(Event::NpcOilWashedAway(npc.id));
+        }
+
         if let Some(poison_effect) = &npc.character.current_effects.poison {
-            let damage = npc.character.get_current_health().min(poison_effect.damage);
+            let resisted_damage =
+                (poison_effect.damage - npc.character.stats.attributes.poison_resistance()).max(1);
+            let damage = npc.character.get_current_health().min(resisted_damage);
             events.push(Event::NpcDamagedByPoison(NpcDamagedByPoison {
                 npc_id: npc.id,
                 damage,
@@ -71,3 +125,75 @@ pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
 
     events
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::{items::Affix, Poison},
+        events::Event,
+        generators::{game::game_generator, generator::Generator, players::player_generator},
+    };
+
+    use super::handle;
+
+    #[test]
+    fn a_festering_curse_does_not_kill_a_player_the_poison_already_killed() {
+        let state = game_generator(None, None, None).generate();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.health.current = 1;
+        player.character.current_effects.poison = Some(Poison {
+            damage: 3,
+            duration: 3,
+        });
+        let character_item = player.character.inventory.equipment.first_mut().unwrap();
+        character_item.at_the_ready = true;
+        character_item.item.affixes.push(Affix::Festering);
+
+        let events = handle(&state, &player);
+
+        assert_eq!(
+            1,
+            events
+                .iter()
+                .filter(|event| matches!(event, Event::PlayerKilled(_)))
+                .count()
+        );
+    }
+
+    #[test]
+    fn a_regenerating_enchantment_keeps_a_festering_curse_at_bay() {
+        let mut state = game_generator(None, None, None).generate();
+        state.current_room_mut().descriptors.clear();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.health.current = 1;
+        player.character.current_effects.poison = None;
+        let character_item = player.character.inventory.equipment.first_mut().unwrap();
+        character_item.at_the_ready = true;
+        character_item.item.affixes = vec![Affix::Regenerating, Affix::Festering];
+
+        let events = handle(&state, &player);
+
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerKilled(_))));
+    }
+
+    #[test]
+    fn resolute_players_shrug_off_some_poison() {
+        let mut state = game_generator(None, None, None).generate();
+        state.current_room_mut().descriptors.clear();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.health.current = player.character.stats.health.max;
+        player.character.stats.attributes.resolve = 10;
+        player.character.current_effects.poison = Some(Poison {
+            damage: 4,
+            duration: 3,
+        });
+
+        let events = handle(&state, &player);
+
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerDamagedByPoison(2))));
+    }
+}
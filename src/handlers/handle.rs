@@ -2,7 +2,7 @@ use uuid::Uuid;
 
 use crate::{
     actions::Action,
-    components::{games::GameState, LifeModifier, PlayerCharacter},
+    components::{games::GameState, items::Affix, LifeModifier, PlayerCharacter},
     errors::Error,
     events::{apply_events, Event, GhostEscapesToTheVoid},
 };
@@ -24,6 +24,45 @@ fn is_being_targeted(action: &Action, npc_id: &Uuid) -> bool {
     }
 }
 
+/// NPCs that attack the player before the action is carried out: any NPC
+/// the action targets, and the first one in the room when the player fiddles
+/// with something instead of fighting. A readied Beckoning curse draws every
+/// NPC in the room instead.
+fn npc_reactions(action: &Action, state: &GameState, player: &PlayerCharacter) -> Vec<NpcAction> {
+    let living_npc_ids = state
+        .current_room()
+        .npc_positions
+        .iter()
+        .filter(|npc_position| !npc_position.npc.character.is_dead())
+        .map(|npc_position| npc_position.npc.id);
+
+    if player.character.has_readied_affix(&Affix::Beckoning) {
+        return living_npc_ids.map(NpcAction::AttackPlayer).collect();
+    }
+
+    let mut npc_actions: Vec<NpcAction> = living_npc_ids
+        .filter(|npc_id| is_being_targeted(action, npc_id))
+        .map(NpcAction::AttackPlayer)
+        .collect();
+
+    match action {
+        Action::DisarmTrap(_)
+        | Action::InspectFixture(_)
+        | Action::OpenFixture(_)
+        | Action::OpenFixtureHiddenCompartment(_)
+        | Action::UnlockExit(_)
+        | Action::SearchRoom(_)
+        | Action::UnlockFixture(_) => {
+            if let Some(npc) = state.current_room().first_alive_npc() {
+                npc_actions.push(NpcAction::AttackPlayer(npc.id));
+            }
+        }
+        _ => {}
+    }
+
+    npc_actions
+}
+
 pub fn handle_action(
     action: &Action,
     state: &GameState,
@@ -35,43 +74,17 @@ pub fn handle_action(
         return Err(Error::PlayerIsDeadError);
     }
 
-    let mut npc_actions: Vec<NpcAction> = Vec::new();
-    for npc_position in state
-        .current_room()
-        .npc_positions
-        .iter()
-        .filter(|npc_position| !npc_position.npc.character.is_dead())
-    {
-        if is_being_targeted(action, &npc_position.npc.id) {
-            npc_actions.push(NpcAction::AttackPlayer(npc_position.npc.id));
-        }
+    if state.is_over() {
+        return Err(Error::GameIsOverError);
     }
 
-    npc_actions.append(&mut match action {
-        Action::InspectFixture(_)
-        | Action::OpenFixture(_)
-        | Action::OpenFixtureHiddenCompartment(_) => {
-            if let Some(npc) = state.current_room().first_alive_npc() {
-                vec![NpcAction::AttackPlayer(npc.id)]
-            } else {
-                Vec::new()
-            }
-        }
-        Action::AttackNpc(_)
-        | Action::CastSpellOnNpc(_)
-        | Action::CastSpellOnPlayer(_)
-        | Action::ExitRoom(_)
-        | Action::InspectNpc(_)
-        | Action::LookAtFixture(_)
-        | Action::LookAtNpc(_)
-        | Action::LootFixture(_)
-        | Action::LootNpc(_)
-        | Action::MovePlayerItem(_)
-        | Action::PickUpItem(_)
-        | Action::SellPlayerItem(_)
-        | Action::UseItemOnPlayer(_)
-        | Action::ThrowItemAtNpc(_) => Vec::new(),
-    });
+    // Travelling is handled one exit at a time, so NPCs and global effects
+    // get their turn in every room along the way.
+    if let Action::TravelToRoom(travel_to_room) = action {
+        return super::travel_to_room::handle(travel_to_room, state, player);
+    }
+
+    let npc_actions = npc_reactions(action, state, player);
 
     let mut events: Vec<Event> = Vec::new();
 
@@ -83,31 +96,38 @@ pub fn handle_action(
     }
 
     events.append(&mut match action {
-        Action::ExitRoom(exit_room) => super::exit_room::handle(exit_room, state)?,
+        Action::ExitRoom(exit_room) => super::exit_room::handle(exit_room, state, player)?,
         Action::AttackNpc(attack_npc) => super::attack_npc::handle(attack_npc, state, player)?,
-        Action::LootNpc(loot_npc) => super::loot_npc::handle(loot_npc, state)?,
+        Action::LootNpc(loot_npc) => super::loot_npc::handle(loot_npc, state, player)?,
         Action::LookAtNpc(look_at_npc) => super::view_npc::handle(look_at_npc, state)?,
         Action::MovePlayerItem(move_player_item) => {
             super::move_player_item::handle(move_player_item, player)?
         }
-        Action::InspectNpc(inspect_npc) => super::inspect_npc::handle(inspect_npc, state)?,
+        Action::InspectItem(inspect_item) => {
+            super::inspect_item::handle(inspect_item, state, player)?
+        }
+        Action::InspectNpc(inspect_npc) => super::inspect_npc::handle(inspect_npc, state, player)?,
         Action::InspectFixture(inspect_fixture) => {
-            super::inspect_fixture::handle(inspect_fixture, state)?
+            super::inspect_fixture::handle(inspect_fixture, state, player)?
         }
         Action::LookAtFixture(look_at_fixture) => {
             super::view_fixture::handle(look_at_fixture, state)?
         }
-        Action::LootFixture(loot_fixture) => super::loot_fixture::handle(loot_fixture, state)?,
+        Action::LootFixture(loot_fixture) => {
+            super::loot_fixture::handle(loot_fixture, state, player)?
+        }
         Action::CastSpellOnNpc(cast_spell_on_npc) => {
             super::cast_spell_on_npc::handle(cast_spell_on_npc, state, player)?
         }
         Action::CastSpellOnPlayer(cast_spell_on_player) => {
-            super::cast_spell_on_player::handle(cast_spell_on_player, player)?
+            super::cast_spell_on_player::handle(cast_spell_on_player, state, player)?
         }
         Action::UseItemOnPlayer(use_item_on_player) => {
             super::use_item_on_player::handle(use_item_on_player, player)?
         }
-        Action::OpenFixture(open_fixture) => super::open_fixture::handle(open_fixture, state)?,
+        Action::OpenFixture(open_fixture) => {
+            super::open_fixture::handle(open_fixture, state, player)?
+        }
         Action::OpenFixtureHiddenCompartment(open_fixture_hidden_compartment) => {
             super::open_fixture_hidden_compartment::handle(open_fixture_hidden_compartment, state)?
         }
@@ -117,9 +137,21 @@ pub fn handle_action(
         Action::ThrowItemAtNpc(throw_item_at_npc) => {
             super::throw_item_at_npc::handle(throw_item_at_npc, state, player)?
         }
-        Action::PickUpItem(pick_up_item) => super::pick_up_item::handle(pick_up_item, state)?,
+        Action::PickUpItem(pick_up_item) => {
+            super::pick_up_item::handle(pick_up_item, state, player)?
+        }
+        Action::RepairItem(repair_item) => super::repair_item::handle(repair_item, state, player)?,
+        Action::DisarmTrap(disarm_trap) => super::disarm_trap::handle(disarm_trap, state, player)?,
+        Action::SearchRoom(search_room) => super::search_room::handle(search_room, state, player)?,
+        Action::TravelToRoom(_) => unreachable!("travelling returns before the turn starts"),
+        Action::UnlockExit(unlock_exit) => super::unlock_exit::handle(unlock_exit, state, player)?,
+        Action::UnlockFixture(unlock_fixture) => {
+            super::unlock_fixture::handle(unlock_fixture, state, player)?
+        }
     });
 
+    events.push(Event::TurnTaken);
+
     let (mut intermediate_state, mut intermediate_player) = apply_events(&events, state, player);
 
     let mut global_events =
@@ -129,12 +161,24 @@ pub fn handle_action(
 
     events.append(&mut global_events);
 
-    let mut dead_events = dead_player_events(&intermediate_player);
+    let mut dead_events = dead_player_events(&events, &intermediate_state, &intermediate_player);
 
-    let (new_state, new_player) =
+    (intermediate_state, intermediate_player) =
         apply_events(&dead_events, &intermediate_state, &intermediate_player);
     events.append(&mut dead_events);
 
+    let mut quest_events =
+        super::quests::handle(&events, &intermediate_state, &intermediate_player);
+    (intermediate_state, intermediate_player) =
+        apply_events(&quest_events, &intermediate_state, &intermediate_player);
+    events.append(&mut quest_events);
+
+    let mut outcome_events =
+        super::outcome::handle(&events, &intermediate_state, &intermediate_player);
+    let (new_state, new_player) =
+        apply_events(&outcome_events, &intermediate_state, &intermediate_player);
+    events.append(&mut outcome_events);
+
     Ok(HandledAction {
         new_state,
         new_player,
@@ -142,19 +186,107 @@ pub fn handle_action(
     })
 }
 
-fn dead_player_events(player: &PlayerCharacter) -> Vec<Event> {
+fn dead_player_events(events: &[Event], state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
     if !player.character.is_dead() {
         return Vec::new();
     }
 
+    // The gear is dropped in the room, so the ghost takes a copy of it with it
+    // to the void instead of the very same items.
     let mut character = player.character.clone();
     character.life_modifier = Some(LifeModifier::Ghost);
+    for character_item in character.inventory.equipment.iter_mut() {
+        character_item.item.id = Uuid::new_v4();
+    }
+
+    let killer_name = events
+        .iter()
+        .rev()
+        .find_map(|event| match event {
+            Event::PlayerKilled(player_killed) => player_killed.killer_id,
+            _ => None,
+        })
+        .and_then(|npc_id| state.find_npc(&npc_id))
+        .and_then(|npc| npc.name.clone());
 
     vec![
         Event::GhostEscapesToTheVoid(GhostEscapesToTheVoid {
             character,
             name: player.name.clone(),
+            killer_name,
         }),
         Event::PlayerDropsAllItems,
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::{Action, LookAtFixture},
+        components::{items::Affix, rooms::NpcPosition},
+        events::Event,
+        generators::{
+            game::game_generator, generator::Generator, non_players::NonPlayerGeneratorBuilder,
+            players::player_generator,
+        },
+    };
+
+    use super::{dead_player_events, npc_reactions, NpcAction};
+
+    #[test]
+    fn a_dead_player_leaves_their_gear_in_the_room_and_takes_a_copy_to_the_void() {
+        let state = game_generator(None, None, None).generate();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.kill();
+
+        let events = dead_player_events(&[], &state, &player);
+
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerDropsAllItems)));
+        let ghost = events
+            .iter()
+            .find_map(|event| match event {
+                Event::GhostEscapesToTheVoid(ghost_escapes) => Some(&ghost_escapes.character),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            player.character.inventory.equipment.len(),
+            ghost.inventory.equipment.len()
+        );
+        assert!(ghost.inventory.equipment.iter().all(|character_item| player
+            .character
+            .find_item(&character_item.item.id)
+            .is_none()));
+    }
+
+    #[test]
+    fn a_beckoning_curse_draws_every_npc_in_the_room() {
+        let mut state = game_generator(None, None, None).generate();
+        let room = state.current_room_mut();
+        room.npc_positions.clear();
+        for _ in 0..3 {
+            room.npc_positions.push(NpcPosition {
+                npc: NonPlayerGeneratorBuilder::new().build().generate(),
+                position_descriptor: None,
+            });
+        }
+        let mut player = player_generator(None, None, None).generate();
+        let character_item = player.character.inventory.equipment.first_mut().unwrap();
+        character_item.at_the_ready = true;
+        character_item.item.affixes.push(Affix::Beckoning);
+        let action = Action::LookAtFixture(LookAtFixture {
+            fixture_id: String::new(),
+        });
+
+        let npc_actions = npc_reactions(&action, &state, &player);
+
+        assert_eq!(3, npc_actions.len());
+        assert!(state.current_room().npc_positions.iter().all(|position| {
+            npc_actions
+                .iter()
+                .any(|NpcAction::AttackPlayer(npc_id)| position.npc.id.eq(npc_id))
+        }));
+    }
+}
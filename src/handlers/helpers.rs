@@ -1,32 +1,132 @@
-use std::ops::RangeInclusive;
-
-use rand::Rng;
+use rand::{prelude::ThreadRng, Rng};
+use uuid::Uuid;
 
 use crate::{
-    components::{damage::AttackEffect, NonPlayer, PlayerCharacter},
+    components::{
+        damage::AttackEffect,
+        games::Ruleset,
+        items::CharacterItem,
+        rooms::{Descriptor, Room},
+        Lock, NonPlayer, PlayerCharacter, Trap,
+    },
+    errors::Error,
     events::{
-        Event, NpcWeaponReadied, PlayerHit, PlayerHitNpc, PlayerKilled, PlayerKilledNpc,
-        PlayerMissed, PlayerPoisoned,
+        Event, NpcItemBroken, NpcItemDestroyed, NpcItemWornDown, NpcWeaponReadied, PlayerHit,
+        PlayerHitNpc, PlayerItemWornDown, PlayerKilled, PlayerKilledNpc, PlayerMissed,
+        PlayerPoisoned, TrapTriggered,
     },
-    utils::rolls::{roll_d6, roll_percent_succeeds},
+    utils::rolls::{roll_d6, roll_percent_succeeds, roll_range},
 };
 
-const PLAYER_DODGE_CHANCE: i32 = 1;
+const ENCUMBERED_DODGE_ROLL_PENALTY: i32 = 1;
+
+const ACID_WEAR: i32 = 10;
+const WEAR_CHANCE: i32 = 33;
+const WEAR_AMOUNT: i32 = 1;
+const LOCK_PICK_ROLLS: usize = 3;
+const DISARM_TRAP_ROLLS: usize = 3;
+const DISCOVER_TRAP_CHANCE: i32 = 4;
+const DARK_MISS_CHANCE: i32 = 25;
+
+pub fn trigger_trap(player: &PlayerCharacter, trap: &Trap) -> Vec<Event> {
+    let mut rng = rand::thread_rng();
+    let damage = roll_d6(&mut rng, trap.damage_rolls, 0)
+        .max(1)
+        .min(player.character.get_current_health());
+    let mut events = vec![Event::TrapTriggered(TrapTriggered {
+        trap_id: trap.id,
+        damage,
+    })];
+
+    // Resolve already takes the edge off each turn of poison, and it also lets the
+    // player shake off a trap's dose sooner, or before it takes hold at all.
+    if let Some(poison) = &trap.poison {
+        let duration = poison.duration - player.character.stats.attributes.poison_resistance();
+        if duration > 0 {
+            events.push(Event::PlayerPoisoned(PlayerPoisoned {
+                damage: poison.damage,
+                duration,
+            }));
+        }
+    }
+
+    if damage >= player.character.get_current_health() {
+        events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
+    }
+
+    events
+}
+
+pub fn discover_trap_succeeds(player: &PlayerCharacter) -> bool {
+    let mut rng = rand::thread_rng();
+    let wits_modifier = player.character.stats.attributes.wits_modifier();
+    roll_d6(&mut rng, 1, wits_modifier) >= DISCOVER_TRAP_CHANCE
+}
+
+/// Anything the player has to aim at an NPC can go astray when the room is dark.
+pub fn misses_in_the_dark(room: &Room) -> bool {
+    let mut rng = rand::thread_rng();
+    room.has_descriptor(&Descriptor::Dark) && roll_percent_succeeds(&mut rng, DARK_MISS_CHANCE)
+}
+
+pub fn disarm_trap_succeeds(player: &PlayerCharacter, trap: &Trap) -> bool {
+    let mut rng = rand::thread_rng();
+    let agility_modifier = player.character.stats.attributes.agility_modifier();
+    roll_d6(&mut rng, DISARM_TRAP_ROLLS, agility_modifier) >= trap.difficulty
+}
 
-const TOXIC_RANGE: RangeInclusive<i32> = 3..=6;
-const TOXIC_DURATION_RANGE: RangeInclusive<i32> = 2..=4;
+pub enum Unlocking {
+    WithKey(Uuid),
+    Picked,
+    PickFailed,
+}
 
-const ACID_DESTROYS_ITEM_CHANCE: i32 = 20;
+/// Opens a lock with its key from the player's inventory, or by rolling
+/// against the lock's difficulty when picking it.
+pub fn unlock(
+    player: &PlayerCharacter,
+    lock: &Lock,
+    pick_lock: bool,
+    target_id: &Uuid,
+) -> Result<Unlocking, Error> {
+    if !lock.locked {
+        return Err(Error::NotLocked(target_id.to_string()));
+    }
+
+    if pick_lock {
+        let mut rng = rand::thread_rng();
+        let agility_modifier = player.character.stats.attributes.agility_modifier();
+        if roll_d6(&mut rng, LOCK_PICK_ROLLS, agility_modifier) >= lock.difficulty {
+            Ok(Unlocking::Picked)
+        } else {
+            Ok(Unlocking::PickFailed)
+        }
+    } else {
+        match lock
+            .key_id
+            .filter(|key_id| player.character.inventory.find_item(key_id).is_some())
+        {
+            Some(key_id) => Ok(Unlocking::WithKey(key_id)),
+            None => Err(Error::KeyNotFound(target_id.to_string())),
+        }
+    }
+}
 
 pub fn npc_attack_player(
     player: &PlayerCharacter,
     npc: &NonPlayer,
     npc_can_ready: bool,
+    ruleset: &Ruleset,
 ) -> Vec<Event> {
     let mut rng = rand::thread_rng();
     let dodge_roll = roll_d6(&mut rng, 1, 0);
+    let mut dodge_chance =
+        ruleset.player_dodge_chance + player.character.stats.attributes.agility_modifier();
+    if player.character.is_encumbered() {
+        dodge_chance -= ENCUMBERED_DODGE_ROLL_PENALTY;
+    }
 
-    if dodge_roll <= PLAYER_DODGE_CHANCE {
+    if dodge_roll <= dodge_chance {
         return vec![Event::PlayerMissed(PlayerMissed {
             attacker_id: npc.id,
         })];
@@ -55,6 +155,18 @@ pub fn npc_attack_player(
             player_damage = actual_damage;
         }
 
+        events.append(&mut npc_items_worn(
+            npc.id,
+            &npc.character.inventory.readied_weapons(),
+            &mut rng,
+        ));
+        if player_defense.damage_resistance > 0 {
+            events.append(&mut player_items_worn(
+                &player.character.inventory.readied_defensive_items(),
+                &mut rng,
+            ));
+        }
+
         if player_damage > 0 {
             events.push(Event::PlayerHit(PlayerHit {
                 attacker_id: npc.id,
@@ -76,17 +188,17 @@ pub fn npc_attack_player(
                 match effect {
                     AttackEffect::Toxic => {
                         events.push(Event::PlayerPoisoned(PlayerPoisoned {
-                            damage: rng.gen_range(TOXIC_RANGE),
-                            duration: rng.gen_range(TOXIC_DURATION_RANGE),
+                            damage: roll_range(&mut rng, &ruleset.toxic_damage),
+                            duration: roll_range(&mut rng, &ruleset.toxic_duration),
                         }));
                     }
                     AttackEffect::Acidic => {
-                        if roll_percent_succeeds(&mut rng, ACID_DESTROYS_ITEM_CHANCE) {
+                        if roll_percent_succeeds(&mut rng, ruleset.npc_acid_destroys_item_chance) {
                             let equipped_items = player.character.inventory.readied_weapons();
                             let index = rng.gen_range(0..equipped_items.len());
                             if let Some(character_item) = equipped_items.get(index) {
                                 events.push(Event::PlayerHitWithAcid);
-                                events.push(Event::PlayerItemDestroyed(character_item.item.id));
+                                events.append(&mut player_item_hit_with_acid(character_item));
                             }
                         }
                     }
@@ -148,3 +260,78 @@ pub fn damage_npc(player: &PlayerCharacter, npc: &NonPlayer, damage: i32) -> (Ve
 
     (events, npc_dead)
 }
+
+/// Each item used in an exchange has a chance to lose some durability.
+pub fn player_items_worn(items: &[CharacterItem], rng: &mut ThreadRng) -> Vec<Event> {
+    items
+        .iter()
+        .filter(|_| roll_percent_succeeds(rng, WEAR_CHANCE))
+        .flat_map(|character_item| player_item_wear(character_item, WEAR_AMOUNT))
+        .collect()
+}
+
+pub fn npc_items_worn(npc_id: Uuid, items: &[CharacterItem], rng: &mut ThreadRng) -> Vec<Event> {
+    items
+        .iter()
+        .filter(|_| roll_percent_succeeds(rng, WEAR_CHANCE))
+        .flat_map(|character_item| npc_item_wear(npc_id, character_item, WEAR_AMOUNT))
+        .collect()
+}
+
+pub fn player_item_wear(character_item: &CharacterItem, amount: i32) -> Vec<Event> {
+    let durability = match &character_item.item.durability {
+        Some(it) if !it.is_broken() => it,
+        _ => return Vec::new(),
+    };
+
+    let mut events = vec![Event::PlayerItemWornDown(PlayerItemWornDown {
+        item_id: character_item.item.id,
+        amount,
+    })];
+    if durability.current <= amount {
+        events.push(Event::PlayerItemBroken(character_item.item.id));
+    }
+
+    events
+}
+
+pub fn npc_item_wear(npc_id: Uuid, character_item: &CharacterItem, amount: i32) -> Vec<Event> {
+    let durability = match &character_item.item.durability {
+        Some(it) if !it.is_broken() => it,
+        _ => return Vec::new(),
+    };
+
+    let mut events = vec![Event::NpcItemWornDown(NpcItemWornDown {
+        npc_id,
+        item_id: character_item.item.id,
+        amount,
+    })];
+    if durability.current <= amount {
+        events.push(Event::NpcItemBroken(NpcItemBroken {
+            npc_id,
+            item_id: character_item.item.id,
+        }));
+    }
+
+    events
+}
+
+/// Acid eats away at an item, destroying it outright if it has no durability to lose.
+pub fn player_item_hit_with_acid(character_item: &CharacterItem) -> Vec<Event> {
+    if character_item.item.durability.is_some() {
+        player_item_wear(character_item, ACID_WEAR)
+    } else {
+        vec![Event::PlayerItemDestroyed(character_item.item.id)]
+    }
+}
+
+pub fn npc_item_hit_with_acid(npc_id: Uuid, character_item: &CharacterItem) -> Vec<Event> {
+    if character_item.item.durability.is_some() {
+        npc_item_wear(npc_id, character_item, ACID_WEAR)
+    } else {
+        vec![Event::NpcItemDestroyed(NpcItemDestroyed {
+            npc_id,
+            item_id: character_item.item.id,
+        })]
+    }
+}
@@ -1,30 +1,46 @@
 use crate::{
     actions::InspectFixture,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
-    events::{Event, FixtureHasHiddenCompartmentDiscovered},
+    events::{Event, FixtureHasHiddenCompartmentDiscovered, TrapDiscovered},
     utils::{ids::parse_id, rolls::roll_d6},
 };
 
+use super::helpers::discover_trap_succeeds;
+
 const DISCOVER_HIDDEN_COMPARTMENT_CHANCE: i32 = 2;
 
-pub fn handle(inspect_fixture: &InspectFixture, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    inspect_fixture: &InspectFixture,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let mut events: Vec<Event> = Vec::new();
     let fixture_id = parse_id(&inspect_fixture.fixture_id)?;
 
-    if state.current_room().find_fixture(&fixture_id).is_none() {
-        return Err(Error::FixtureNotFoundError(fixture_id.to_string()));
-    }
+    let fixture_position = match state.current_room().find_fixture(&fixture_id) {
+        Some(it) => it,
+        None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
+    };
 
     let mut rng = rand::thread_rng();
+    let wits_modifier = player.character.stats.attributes.wits_modifier();
 
     if inspect_fixture.discover_hidden_compartment
-        && roll_d6(&mut rng, 1, 0) >= DISCOVER_HIDDEN_COMPARTMENT_CHANCE
+        && roll_d6(&mut rng, 1, wits_modifier) >= DISCOVER_HIDDEN_COMPARTMENT_CHANCE
     {
         events.push(Event::FixtureHasHiddenCompartmentDiscovered(
             FixtureHasHiddenCompartmentDiscovered { fixture_id },
         ));
     }
 
+    if inspect_fixture.discover_trap {
+        if let Some(trap) = &fixture_position.fixture.trap {
+            if trap.armed && !state.knows_trap(&trap.id) && discover_trap_succeeds(player) {
+                events.push(Event::TrapDiscovered(TrapDiscovered { trap_id: trap.id }));
+            }
+        }
+    }
+
     Ok(events)
 }
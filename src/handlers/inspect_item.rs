@@ -0,0 +1,115 @@
+use uuid::Uuid;
+
+use crate::{
+    actions::InspectItem,
+    components::{games::GameState, items::Item, PlayerCharacter},
+    errors::Error,
+    events::{
+        Event, ItemAffixesDiscovered, ItemAttackDiscovered, ItemConsumableDiscovered,
+        ItemDefenseDiscovered,
+    },
+    utils::{ids::parse_id, rolls::roll_d6},
+};
+
+const DISCOVER_ATTACK_CHANCE: i32 = 4;
+const DISCOVER_DEFENSE_CHANCE: i32 = 4;
+const DISCOVER_CONSUMABLE_CHANCE: i32 = 5;
+const DISCOVER_AFFIXES_CHANCE: i32 = 5;
+
+pub fn handle(
+    inspect_item: &InspectItem,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let item_id = parse_id(&inspect_item.item_id)?;
+
+    let item = match find_visible_item(&item_id, state, player) {
+        Some(it) => it,
+        None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+    };
+
+    let mut events: Vec<Event> = Vec::new();
+    let mut rng = rand::thread_rng();
+    let wits_modifier = player.character.stats.attributes.wits_modifier();
+
+    if inspect_item.discover_attack
+        && item.attack.is_some()
+        && roll_d6(&mut rng, 1, wits_modifier) >= DISCOVER_ATTACK_CHANCE
+    {
+        events.push(Event::ItemAttackDiscovered(ItemAttackDiscovered {
+            item_id,
+        }));
+    }
+
+    if inspect_item.discover_defense
+        && item.defense.is_some()
+        && roll_d6(&mut rng, 1, wits_modifier) >= DISCOVER_DEFENSE_CHANCE
+    {
+        events.push(Event::ItemDefenseDiscovered(ItemDefenseDiscovered {
+            item_id,
+        }));
+    }
+
+    if inspect_item.discover_consumable
+        && item.consumable.is_some()
+        && roll_d6(&mut rng, 1, wits_modifier) >= DISCOVER_CONSUMABLE_CHANCE
+    {
+        events.push(Event::ItemConsumableDiscovered(ItemConsumableDiscovered {
+            item_id,
+        }));
+    }
+
+    if inspect_item.discover_affixes
+        && roll_d6(&mut rng, 1, wits_modifier) >= DISCOVER_AFFIXES_CHANCE
+    {
+        events.push(Event::ItemAffixesDiscovered(ItemAffixesDiscovered {
+            item_id,
+        }));
+    }
+
+    Ok(events)
+}
+
+/// Items the player carries, items lying loose, items visible in fixtures and
+/// items on NPCs in the current room can all be looked over.
+fn find_visible_item(item_id: &Uuid, state: &GameState, player: &PlayerCharacter) -> Option<Item> {
+    if let Some(character_item) = player.character.find_item(item_id) {
+        return Some(character_item.item);
+    }
+
+    let room = state.current_room();
+
+    if let Some(item) = room.loose_items.iter().find(|item| item.id.eq(item_id)) {
+        return Some(item.clone());
+    }
+
+    let fixture_item = room
+        .fixture_positions
+        .iter()
+        .map(|fixture_position| &fixture_position.fixture)
+        .flat_map(|fixture| {
+            fixture.items.iter().filter(|fixture_item| {
+                (!fixture_item.is_inside || fixture.open)
+                    && (!fixture_item.is_in_hidden_compartment || fixture.hidden_compartment_open)
+            })
+        })
+        .find(|fixture_item| fixture_item.item.id.eq(item_id));
+    if let Some(fixture_item) = fixture_item {
+        return Some(fixture_item.item.clone());
+    }
+
+    room.npc_positions
+        .iter()
+        .map(|npc_position| &npc_position.npc)
+        .find_map(|npc| {
+            let knowledge = state.npc_knowledge(&npc.id);
+            npc.character
+                .find_item(item_id)
+                .filter(|character_item| {
+                    character_item.is_at_the_ready()
+                        || knowledge.knows_packed_in_inventory
+                        || npc.character.is_dead()
+                })
+                .map(|character_item| character_item.item)
+        })
+}
@@ -1,15 +1,16 @@
 use crate::{
     actions::InspectNpc,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
-    events::{Event, NpcHealthDiscovered, NpcPackedDiscovered},
+    events::{Event, NpcAttributesDiscovered, NpcHealthDiscovered, NpcPackedDiscovered},
     utils::{ids::parse_id, rolls::roll_d6},
 };
 
-const DISCOVER_HEALTH_CHANCE: i32 = 5;
-const DISCOVER_PACKED_CHANCE: i32 = 4;
-
-pub fn handle(inspect_npc: &InspectNpc, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    inspect_npc: &InspectNpc,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let mut events: Vec<Event> = Vec::new();
     let npc_id = parse_id(&inspect_npc.npc_id)?;
 
@@ -21,16 +22,32 @@ pub fn handle(inspect_npc: &InspectNpc, state: &GameState) -> Result<Vec<Event>,
     if npc.character.is_dead() {
         events.push(Event::NpcHealthDiscovered(NpcHealthDiscovered { npc_id }));
         events.push(Event::NpcPackedDiscovered(NpcPackedDiscovered { npc_id }));
+        events.push(Event::NpcAttributesDiscovered(NpcAttributesDiscovered {
+            npc_id,
+        }));
     } else {
         let mut rng = rand::thread_rng();
+        let wits_modifier = player.character.stats.attributes.wits_modifier();
 
-        if inspect_npc.discover_health && roll_d6(&mut rng, 1, 0) >= DISCOVER_HEALTH_CHANCE {
+        if inspect_npc.discover_health
+            && roll_d6(&mut rng, 1, wits_modifier) >= state.ruleset.discover_health_chance
+        {
             events.push(Event::NpcHealthDiscovered(NpcHealthDiscovered { npc_id }));
         }
 
-        if inspect_npc.discover_packed_items && roll_d6(&mut rng, 1, 0) >= DISCOVER_PACKED_CHANCE {
+        if inspect_npc.discover_packed_items
+            && roll_d6(&mut rng, 1, wits_modifier) >= state.ruleset.discover_packed_chance
+        {
             events.push(Event::NpcPackedDiscovered(NpcPackedDiscovered { npc_id }));
         }
+
+        if inspect_npc.discover_attributes
+            && roll_d6(&mut rng, 1, wits_modifier) >= state.ruleset.discover_attributes_chance
+        {
+            events.push(Event::NpcAttributesDiscovered(NpcAttributesDiscovered {
+                npc_id,
+            }));
+        }
     }
 
     Ok(events)
@@ -2,13 +2,19 @@ use uuid::Uuid;
 
 use crate::{
     actions::LootFixture,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
     events::{Event, ItemTakenFromFixture},
     utils::ids::parse_id,
 };
 
-pub fn handle(loot_fixture: &LootFixture, state: &GameState) -> Result<Vec<Event>, Error> {
+use super::helpers::trigger_trap;
+
+pub fn handle(
+    loot_fixture: &LootFixture,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let fixture_id = parse_id(&loot_fixture.fixture_id)?;
     let fixture_position = match state.current_room().find_fixture(&fixture_id) {
         Some(it) => it,
@@ -30,6 +36,8 @@ pub fn handle(loot_fixture: &LootFixture, state: &GameState) -> Result<Vec<Event
         .filter(|fixture_item| item_ids.contains(&fixture_item.item.id));
 
     let mut events: Vec<Event> = Vec::new();
+    let mut reaches_inside = false;
+    let mut looted_weight = 0;
     for matching_item in matching_items {
         if (matching_item.is_in_hidden_compartment
             && !fixture_position.fixture.hidden_compartment_open)
@@ -40,11 +48,31 @@ pub fn handle(loot_fixture: &LootFixture, state: &GameState) -> Result<Vec<Event
             ));
         }
 
+        reaches_inside |= matching_item.is_inside || matching_item.is_in_hidden_compartment;
+        looted_weight += matching_item.item.weight();
+        if !player.character.can_carry(looted_weight) {
+            return Err(Error::TooHeavy(matching_item.item.id.to_string()));
+        }
+
         events.push(Event::ItemTakenFromFixture(ItemTakenFromFixture {
             fixture_id,
             item_id: matching_item.item.id,
         }));
     }
 
+    if reaches_inside {
+        if let Some(trap) = fixture_position.fixture.trap.iter().find(|trap| trap.armed) {
+            let mut trap_events = trigger_trap(player, trap);
+            if trap_events
+                .iter()
+                .any(|event| matches!(event, Event::PlayerKilled(_)))
+            {
+                return Ok(trap_events);
+            }
+            trap_events.append(&mut events);
+            events = trap_events;
+        }
+    }
+
     Ok(events)
 }
@@ -1,12 +1,14 @@
 mod attack_npc;
 mod cast_spell_on_npc;
 mod cast_spell_on_player;
+mod disarm_trap;
 mod exit_room;
 mod global_effects;
 mod handle;
 mod handle_npc_action;
 mod helpers;
 mod inspect_fixture;
+mod inspect_item;
 mod inspect_npc;
 mod loot_fixture;
 mod loot_npc;
@@ -14,9 +16,16 @@ mod move_player_item;
 mod npc_action;
 mod open_fixture;
 mod open_fixture_hidden_compartment;
+mod outcome;
 mod pick_up_item;
+mod quests;
+mod repair_item;
+mod search_room;
 mod sell_player_item;
 mod throw_item_at_npc;
+mod travel_to_room;
+mod unlock_exit;
+mod unlock_fixture;
 mod use_item_on_player;
 mod view_fixture;
 mod view_npc;
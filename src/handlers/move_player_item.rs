@@ -1,9 +1,13 @@
-use rand::Rng;
+use rand::{prelude::ThreadRng, Rng};
 
 use crate::{
     actions::MovePlayerItem,
     components::{
-        items::{packed_tags_for_item_type, ready_tag_for_item_type},
+        items::{
+            equip_layer_for_item_type, hands_needed_for_item_type, packed_tags_for_item_type,
+            ready_tag_for_item_type, slots_conflict, Affix, CharacterItem, EquipLayer, ItemType,
+            LocationTag, HANDS_AVAILABLE,
+        },
         PlayerCharacter,
     },
     errors::Error,
@@ -32,37 +36,205 @@ pub fn handle(
         return Ok(Vec::new());
     }
 
-    if character_item.is_weapon()
-        && player.character.count_weapons_at_ready() >= MAX_WEAPONS_AT_READY
-        && move_player_item.put_at_the_ready
-    {
-        return Err(Error::TooManyWeaponsEquippedError);
-    }
-
-    if character_item.is_wearable()
-        && player.character.count_wearables_at_ready() >= MAX_WEARABLES_AT_READY
-        && move_player_item.put_at_the_ready
-    {
-        return Err(Error::TooManyWearablesEquippedError);
+    if character_item.is_at_the_ready() && character_item.item.has_affix(&Affix::Binding) {
+        return Err(Error::ItemIsBound(item_id.to_string()));
     }
 
+    let mut rng = rand::thread_rng();
     let location = match &move_player_item.location_tag {
         Some(it) => *it,
         None => {
             if move_player_item.put_at_the_ready {
                 ready_tag_for_item_type(&character_item.item.item_type)
             } else {
-                let mut rng = rand::thread_rng();
-                let possibilities = packed_tags_for_item_type(&character_item.item.item_type);
-                let index = rng.gen_range(0..possibilities.len());
-                possibilities.get(index).cloned().unwrap()
+                packed_location(&character_item.item.item_type, &mut rng)
             }
         }
     };
 
-    Ok(vec![Event::PlayerItemMoved(PlayerItemMoved {
+    let mut events: Vec<Event> = Vec::new();
+
+    if move_player_item.put_at_the_ready {
+        let to_swap = items_to_swap(player, &character_item, &location);
+
+        let swapped_weapons = to_swap.iter().filter(|item| item.is_weapon()).count();
+        if character_item.is_weapon()
+            && player.character.count_weapons_at_ready() - swapped_weapons >= MAX_WEAPONS_AT_READY
+        {
+            return Err(Error::TooManyWeaponsEquippedError);
+        }
+
+        let swapped_wearables = to_swap.iter().filter(|item| item.is_wearable()).count();
+        if character_item.is_wearable()
+            && player.character.count_wearables_at_ready() - swapped_wearables
+                >= MAX_WEARABLES_AT_READY
+        {
+            return Err(Error::TooManyWearablesEquippedError);
+        }
+
+        if let Some(bound) = to_swap
+            .iter()
+            .find(|item| item.item.has_affix(&Affix::Binding))
+        {
+            return Err(Error::ItemIsBound(bound.item.id.to_string()));
+        }
+
+        for swapped in to_swap {
+            events.push(Event::PlayerItemMoved(PlayerItemMoved {
+                item_id: swapped.item.id,
+                at_the_ready: false,
+                location: packed_location(&swapped.item.item_type, &mut rng),
+            }));
+        }
+    }
+
+    events.push(Event::PlayerItemMoved(PlayerItemMoved {
         item_id,
         at_the_ready: move_player_item.put_at_the_ready,
         location,
-    })])
+    }));
+
+    Ok(events)
+}
+
+fn packed_location(item_type: &ItemType, rng: &mut ThreadRng) -> LocationTag {
+    let possibilities = packed_tags_for_item_type(item_type);
+    let index = rng.gen_range(0..possibilities.len());
+    possibilities.get(index).cloned().unwrap()
+}
+
+/// Items at the ready that need to be packed away so the new item can take their
+/// place. Held items are packed until there are enough free hands.
+fn items_to_swap(
+    player: &PlayerCharacter,
+    character_item: &CharacterItem,
+    location: &LocationTag,
+) -> Vec<CharacterItem> {
+    let item_type = &character_item.item.item_type;
+    let at_the_ready = player
+        .character
+        .inventory
+        .equipment
+        .iter()
+        .filter(|other| other.at_the_ready && other.item.id.ne(&character_item.item.id));
+
+    if equip_layer_for_item_type(item_type) != EquipLayer::Held {
+        return at_the_ready
+            .filter(|other| {
+                slots_conflict(
+                    location,
+                    item_type,
+                    &other.equipped_location,
+                    &other.item.item_type,
+                )
+            })
+            .cloned()
+            .collect();
+    }
+
+    let held: Vec<&CharacterItem> = at_the_ready
+        .filter(|other| hands_needed_for_item_type(&other.item.item_type) > 0)
+        .collect();
+    let hands_in_use: usize = held
+        .iter()
+        .map(|other| hands_needed_for_item_type(&other.item.item_type))
+        .sum();
+    let mut hands_to_free =
+        (hands_in_use + hands_needed_for_item_type(item_type)).saturating_sub(HANDS_AVAILABLE);
+
+    let mut to_swap: Vec<CharacterItem> = Vec::new();
+    for other in held {
+        if hands_to_free == 0 {
+            break;
+        }
+
+        hands_to_free =
+            hands_to_free.saturating_sub(hands_needed_for_item_type(&other.item.item_type));
+        to_swap.push(other.clone());
+    }
+
+    to_swap
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        actions::MovePlayerItem,
+        components::items::{Affix, CharacterItem, ItemType, LocationTag},
+        errors::Error,
+        events::{Event, PlayerItemMoved},
+        generators::{
+            generator::Generator, items::item_generator_for_level, players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn a_bound_item_cannot_be_put_away() {
+        let mut player = player_generator(None, None, None).generate();
+        let character_item = player.character.inventory.equipment.first_mut().unwrap();
+        character_item.at_the_ready = true;
+        character_item.item.affixes.push(Affix::Binding);
+        let move_player_item = MovePlayerItem {
+            item_id: character_item.item.id.to_string(),
+            location_tag: None,
+            put_at_the_ready: false,
+        };
+
+        let result = handle(&move_player_item, &player);
+
+        assert!(matches!(result, Err(Error::ItemIsBound(_))));
+    }
+
+    #[test]
+    fn readying_a_crown_packs_away_the_helmet_in_its_slot() {
+        let mut player = player_generator(None, None, None).generate();
+        player.character.inventory.equipment.clear();
+        let helmet =
+            item_generator_for_level(&ItemType::PlateHelmet, true, 1, &Arc::default()).generate();
+        let helmet_id = helmet.id;
+        player.character.add_item(CharacterItem {
+            item: helmet,
+            equipped_location: LocationTag::Head,
+            at_the_ready: true,
+        });
+        let crown =
+            item_generator_for_level(&ItemType::Crown, false, 1, &Arc::default()).generate();
+        let crown_id = crown.id;
+        player.character.add_item(CharacterItem {
+            item: crown,
+            equipped_location: LocationTag::Packed,
+            at_the_ready: false,
+        });
+
+        let events = handle(
+            &MovePlayerItem {
+                item_id: crown_id.to_string(),
+                location_tag: Some(LocationTag::Head),
+                put_at_the_ready: true,
+            },
+            &player,
+        )
+        .unwrap();
+
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerItemMoved(PlayerItemMoved {
+                item_id,
+                at_the_ready: false,
+                ..
+            }) if item_id.eq(&helmet_id)
+        )));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerItemMoved(PlayerItemMoved {
+                item_id,
+                at_the_ready: true,
+                location: LocationTag::Head,
+            }) if item_id.eq(&crown_id)
+        )));
+    }
 }
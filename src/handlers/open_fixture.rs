@@ -1,12 +1,18 @@
 use crate::{
     actions::OpenFixture,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
     events::{Event, FixtureOpened},
     utils::ids::parse_id,
 };
 
-pub fn handle(open_fixture: &OpenFixture, state: &GameState) -> Result<Vec<Event>, Error> {
+use super::helpers::trigger_trap;
+
+pub fn handle(
+    open_fixture: &OpenFixture,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let fixture_id = parse_id(&open_fixture.fixture_id)?;
     let fixture_position = match state.current_room().find_fixture(&fixture_id) {
         Some(it) => it,
@@ -17,5 +23,21 @@ pub fn handle(open_fixture: &OpenFixture, state: &GameState) -> Result<Vec<Event
         return Err(Error::FixtureCannotBeOpened(fixture_id.to_string()));
     }
 
-    Ok(vec![Event::FixtureOpened(FixtureOpened { fixture_id })])
+    if fixture_position.fixture.is_locked() {
+        return Err(Error::FixtureIsLocked(fixture_id.to_string()));
+    }
+
+    let mut events = match &fixture_position.fixture.trap {
+        Some(trap) if trap.armed => trigger_trap(player, trap),
+        _ => Vec::new(),
+    };
+    if events
+        .iter()
+        .any(|event| matches!(event, Event::PlayerKilled(_)))
+    {
+        return Ok(events);
+    }
+    events.push(Event::FixtureOpened(FixtureOpened { fixture_id }));
+
+    Ok(events)
 }
@@ -28,7 +28,52 @@ pub fn handle(
         return Err(Error::FixtureHasNoHiddenCompartment(fixture_id.to_string()));
     }
 
+    if fixture_position.fixture.is_locked() {
+        return Err(Error::FixtureIsLocked(fixture_id.to_string()));
+    }
+
     Ok(vec![Event::FixtureHiddenCompartmentOpened(
         FixtureHiddenCompartmentOpened { fixture_id },
     )])
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::OpenFixtureHiddenCompartment,
+        components::{fixtures::FixtureType, rooms::FixturePosition, Lock},
+        errors::Error,
+        generators::{fixtures::get_generator, game::game_generator, generator::Generator},
+    };
+
+    use super::handle;
+
+    #[test]
+    fn a_locked_fixture_keeps_its_hidden_compartment_shut() {
+        let mut state = game_generator(None, None, None).generate();
+        state.all_knowledge_unlocked = true;
+        let mut fixture = get_generator(&FixtureType::Chest, true).generate();
+        fixture.lock = Some(Lock {
+            locked: true,
+            difficulty: 10,
+            key_id: None,
+        });
+        let fixture_id = fixture.id;
+        state
+            .current_room_mut()
+            .fixture_positions
+            .push(FixturePosition {
+                fixture,
+                position_descriptor: None,
+            });
+
+        let result = handle(
+            &OpenFixtureHiddenCompartment {
+                fixture_id: fixture_id.to_string(),
+            },
+            &state,
+        );
+
+        assert!(matches!(result, Err(Error::FixtureIsLocked(_))));
+    }
+}
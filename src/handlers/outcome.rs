@@ -0,0 +1,108 @@
+use crate::{
+    components::{
+        games::{GameOutcome, GameState, VictoryCondition},
+        PlayerCharacter,
+    },
+    events::{Event, GameEnded},
+};
+
+/// Ends the game once the player has died or met one of the game's victory
+/// conditions. The state and player are the ones after the events have been applied.
+pub fn handle(events: &[Event], state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
+    if state.is_over() {
+        return Vec::new();
+    }
+
+    let (victory, killer_id) = if player.character.is_dead() {
+        let killer_id = events.iter().rev().find_map(|event| match event {
+            Event::PlayerKilled(player_killed) => Some(player_killed.killer_id),
+            _ => None,
+        });
+        (None, killer_id.flatten())
+    } else {
+        match victory(events, state) {
+            Some(condition) => (Some(condition), None),
+            None => return Vec::new(),
+        }
+    };
+
+    vec![Event::GameEnded(GameEnded {
+        outcome: GameOutcome {
+            victory,
+            statistics: state.player_stats(&player.id),
+            rooms_seen: state.rooms_seen.len(),
+            gold: player.gold,
+            danger_level: state.danger_level,
+            deepest_depth: state.world.deepest_depth,
+            killer_id,
+            killer_name: killer_id
+                .and_then(|npc_id| state.find_npc(&npc_id))
+                .and_then(|npc| npc.name.clone()),
+        },
+    })]
+}
+
+fn victory(events: &[Event], state: &GameState) -> Option<VictoryCondition> {
+    let conditions = &state.victory_conditions;
+
+    if conditions.escape_depth.is_some()
+        && events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerEscaped(_)))
+    {
+        return Some(VictoryCondition::Escape);
+    }
+
+    if conditions
+        .target_danger_level
+        .is_some_and(|danger_level| state.danger_level >= danger_level)
+    {
+        return Some(VictoryCondition::ReachDangerLevel);
+    }
+
+    // Bosses never spawn dead, so a dead one has been defeated.
+    if conditions.defeat_boss
+        && state
+            .world
+            .rooms
+            .iter()
+            .flat_map(|room| room.npc_positions.iter())
+            .any(|position| position.npc.is_boss() && position.npc.character.is_dead())
+    {
+        return Some(VictoryCondition::DefeatBoss);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::{Action, SearchRoom},
+        components::games::VictoryCondition,
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{game::game_generator, generator::Generator, players::player_generator},
+        handlers::handle_action,
+    };
+
+    use super::handle;
+
+    #[test]
+    fn reaching_the_target_danger_level_wins_and_ends_the_game() {
+        let mut state = game_generator(None, None, None).generate();
+        let player = player_generator(None, None, None).generate();
+        state.victory_conditions.target_danger_level = Some(state.danger_level);
+
+        let events = handle(&[], &state, &player);
+        let outcome = match events.first() {
+            Some(Event::GameEnded(game_ended)) => game_ended.outcome.clone(),
+            _ => panic!("expected the game to end"),
+        };
+        assert_eq!(Some(VictoryCondition::ReachDangerLevel), outcome.victory);
+
+        let (state, player) = apply_events(&events, &state, &player);
+        let result = handle_action(&Action::SearchRoom(SearchRoom {}), &state, &player);
+        assert!(matches!(result, Err(Error::GameIsOverError)));
+    }
+}
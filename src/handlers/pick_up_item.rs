@@ -1,20 +1,81 @@
 use crate::{
-    actions::PickUpItem, components::games::GameState, errors::Error, events::Event,
+    actions::PickUpItem,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::Event,
     utils::parse_id,
 };
 
-pub fn handle(pick_up_item: &PickUpItem, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    pick_up_item: &PickUpItem,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let item_id = parse_id(&pick_up_item.item_id)?;
 
-    match state
+    let item = match state
         .current_room()
         .loose_items
         .iter()
         .find(|item| item.id.eq(&item_id))
     {
-        Some(_) => {}
+        Some(it) => it,
         None => return Err(Error::ItemNotFoundError(item_id.to_string())),
     };
 
+    if !player.character.can_carry(item.weight()) {
+        return Err(Error::TooHeavy(item_id.to_string()));
+    }
+
     Ok(vec![Event::PlayerPicksUpItem(item_id)])
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use crate::{
+        actions::PickUpItem,
+        components::items::{CharacterItem, ItemType, LocationTag},
+        errors::Error,
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn nothing_more_can_be_picked_up_past_the_carry_capacity() {
+        let mut state = game_generator(None, None, None).generate();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.inventory.equipment.clear();
+        let breastplate =
+            item_generator_for_level(&ItemType::Breastplate, false, 1, &Arc::default()).generate();
+        let pick_up_item = PickUpItem {
+            item_id: breastplate.id.to_string(),
+        };
+        state
+            .current_room_mut()
+            .loose_items
+            .push(breastplate.clone());
+
+        assert!(handle(&pick_up_item, &state, &player).is_ok());
+
+        while player.character.can_carry(breastplate.weight()) {
+            let mut carried = breastplate.clone();
+            carried.id = Uuid::new_v4();
+            player.character.add_item(CharacterItem {
+                item: carried,
+                equipped_location: LocationTag::Packed,
+                at_the_ready: false,
+            });
+        }
+
+        let result = handle(&pick_up_item, &state, &player);
+        assert!(matches!(result, Err(Error::TooHeavy(_))));
+    }
+}
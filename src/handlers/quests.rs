@@ -0,0 +1,294 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        games::GameState,
+        quests::{Objective, Quest, Reward},
+        NonPlayer, PlayerCharacter,
+    },
+    events::{
+        EscortDamaged, Event, PlayerGainedItem, PlayerSpellLearned, QuestCompleted, QuestFailed,
+        QuestProgressed, QuestStarted,
+    },
+    generators::quests::generate_quest,
+    utils::rolls::roll_percent_succeeds,
+};
+
+const QUEST_CHANCE: i32 = 25;
+const MAX_ACTIVE_QUESTS: usize = 3;
+const ESCORT_ATTACKED_CHANCE: i32 = 20;
+
+/// Watches everything that happened in a turn for anything that moves the
+/// player's quests along, and sometimes offers a new quest in a room seen for
+/// the first time. The state is the one after the events have been applied.
+/// Nothing moves along once the player is dead.
+pub fn handle(events: &[Event], state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
+    let mut quest_events: Vec<Event> = Vec::new();
+    if player.character.is_dead() {
+        return quest_events;
+    }
+
+    for quest in state.active_quests() {
+        if let Objective::EscortNpc { npc, .. } = &quest.objective {
+            let mut escort_events = escort_attacked(quest, npc, state);
+            let failed = escort_events
+                .iter()
+                .any(|event| matches!(event, Event::QuestFailed(_)));
+            quest_events.append(&mut escort_events);
+            if failed {
+                continue;
+            }
+        }
+
+        let progress = progress(quest, events, state).min(quest.objective.goal());
+        if progress == quest.progress {
+            continue;
+        }
+
+        quest_events.push(Event::QuestProgressed(QuestProgressed {
+            quest_id: quest.id,
+            progress,
+        }));
+
+        if progress >= quest.objective.goal() {
+            quest_events.push(Event::QuestCompleted(QuestCompleted { quest_id: quest.id }));
+            quest_events.push(reward_event(&quest.reward));
+        }
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut num_active = state.active_quests().count();
+    for event in events {
+        if let Event::RoomFirstSeen(room_first_seen) = event {
+            if num_active >= MAX_ACTIVE_QUESTS || !roll_percent_succeeds(&mut rng, QUEST_CHANCE) {
+                continue;
+            }
+
+            if let Some(room) = state
+                .world
+                .rooms
+                .iter()
+                .find(|room| room.id.eq(&room_first_seen.room_id))
+            {
+                quest_events.push(Event::QuestStarted(QuestStarted {
+                    quest: generate_quest(room, state.danger_level, &state.content_pack),
+                }));
+                num_active += 1;
+            }
+        }
+    }
+
+    quest_events
+}
+
+fn progress(quest: &Quest, events: &[Event], state: &GameState) -> u32 {
+    match &quest.objective {
+        Objective::KillSpecies { species, .. } => {
+            let kills = events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::PlayerKilledNpc(killed) => Some(killed.npc_id),
+                    _ => None,
+                })
+                .filter(|npc_id| {
+                    state
+                        .world
+                        .rooms
+                        .iter()
+                        .find_map(|room| room.find_npc(npc_id))
+                        .is_some_and(|npc| npc.character.species.eq(species))
+                })
+                .count() as u32;
+            quest.progress + kills
+        }
+        Objective::RetrieveItem { item_id, .. } => {
+            let retrieved = events.iter().any(|event| match event {
+                Event::ItemTakenFromFixture(taken) => taken.item_id.eq(item_id),
+                _ => false,
+            });
+            if retrieved {
+                1
+            } else {
+                quest.progress
+            }
+        }
+        Objective::ReachDepth { .. } | Objective::EscortNpc { .. } => {
+            if events
+                .iter()
+                .any(|event| matches!(event, Event::RoomExited(_)))
+            {
+                quest.progress.max(state.current_room().depth)
+            } else {
+                quest.progress
+            }
+        }
+    }
+}
+
+/// Anything alive in the room the player is in may turn on the escort instead.
+fn escort_attacked(quest: &Quest, escort: &NonPlayer, state: &GameState) -> Vec<Event> {
+    let mut rng = rand::thread_rng();
+    let mut events: Vec<Event> = Vec::new();
+    let mut health = escort.character.get_current_health();
+
+    for npc in state
+        .current_room()
+        .npc_positions
+        .iter()
+        .map(|npc_position| &npc_position.npc)
+        .filter(|npc| !npc.character.is_dead())
+    {
+        if !roll_percent_succeeds(&mut rng, ESCORT_ATTACKED_CHANCE) {
+            continue;
+        }
+
+        let attack_damage = npc.character.full_attack().attack_damage(&mut rng);
+        let damage = escort
+            .character
+            .full_defense()
+            .calculate_damage_taken(&attack_damage)
+            .min(health);
+        if damage <= 0 {
+            continue;
+        }
+
+        events.push(Event::EscortDamaged(EscortDamaged {
+            quest_id: quest.id,
+            attacker_id: npc.id,
+            damage,
+        }));
+        health -= damage;
+        if health == 0 {
+            events.push(Event::QuestFailed(QuestFailed { quest_id: quest.id }));
+            break;
+        }
+    }
+
+    events
+}
+
+fn reward_event(reward: &Reward) -> Event {
+    match reward {
+        Reward::Gold(gold) => Event::PlayerGainedGold(*gold),
+        Reward::Item(item) => Event::PlayerGainedItem(PlayerGainedItem {
+            item: item.as_ref().clone(),
+        }),
+        Reward::Spell(spell) => Event::PlayerSpellLearned(PlayerSpellLearned {
+            spell_id: Uuid::new_v4(),
+            learned_at: Utc::now(),
+            spell: spell.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        components::quests::{Objective, Quest, Reward},
+        events::{
+            apply_events, EscortDamaged, Event, ItemTakenFromFixture, QuestFailed, RoomExited,
+        },
+        generators::{
+            game::game_generator, generator::Generator, non_players::NonPlayerGeneratorBuilder,
+            players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn retrieving_the_item_completes_the_quest() {
+        let mut state = game_generator(None, None, None).generate();
+        let item_id = Uuid::new_v4();
+        let fixture_id = Uuid::new_v4();
+        state.quests.push(Quest {
+            id: Uuid::new_v4(),
+            objective: Objective::RetrieveItem {
+                item_id,
+                item_name: "Grob's Fang".to_string(),
+                fixture_id,
+            },
+            reward: Reward::Gold(10),
+            progress: 0,
+            completed: false,
+            failed: false,
+        });
+
+        let events = handle(
+            &[Event::ItemTakenFromFixture(ItemTakenFromFixture {
+                fixture_id,
+                item_id,
+            })],
+            &state,
+            &player_generator(None, None, None).generate(),
+        );
+
+        assert!(matches!(
+            events.as_slice(),
+            [
+                Event::QuestProgressed(_),
+                Event::QuestCompleted(_),
+                Event::PlayerGainedGold(10)
+            ]
+        ));
+    }
+
+    #[test]
+    fn a_dead_player_gets_no_reward() {
+        let mut state = game_generator(None, None, None).generate();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.kill();
+        state.quests.push(Quest {
+            id: Uuid::new_v4(),
+            objective: Objective::ReachDepth { depth: 0 },
+            reward: Reward::Gold(10),
+            progress: 0,
+            completed: false,
+            failed: false,
+        });
+        let events = [Event::RoomExited(RoomExited {
+            exit_id: Uuid::new_v4(),
+            old_room_id: state.current_room_id,
+            new_room_id: state.current_room_id,
+        })];
+
+        assert!(handle(&events, &state, &player).is_empty());
+    }
+
+    #[test]
+    fn an_escort_that_died_fails_the_quest() {
+        let mut state = game_generator(None, None, None).generate();
+        let player = player_generator(None, None, None).generate();
+        let mut npc = NonPlayerGeneratorBuilder::new().build().generate();
+        npc.character.stats.health.current = 1;
+        let quest_id = Uuid::new_v4();
+        state.quests.push(Quest {
+            id: quest_id,
+            objective: Objective::EscortNpc {
+                npc: Box::new(npc),
+                depth: 1,
+            },
+            reward: Reward::Gold(10),
+            progress: 0,
+            completed: false,
+            failed: false,
+        });
+
+        let events = [
+            Event::EscortDamaged(EscortDamaged {
+                quest_id,
+                attacker_id: Uuid::new_v4(),
+                damage: 1,
+            }),
+            Event::QuestFailed(QuestFailed { quest_id }),
+        ];
+        let (mut state, player) = apply_events(&events, &state, &player);
+
+        assert!(state.active_quests().next().is_none());
+        assert!(state.quests[0].escort_mut().unwrap().character.is_dead());
+        assert!(handle(&[], &state, &player).is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    actions::RepairItem,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, PlayerItemRepaired},
+    utils::ids::parse_id,
+};
+
+pub fn handle(
+    repair_item: &RepairItem,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let item_id = parse_id(&repair_item.item_id)?;
+    let character_item = match player.character.find_item(&item_id) {
+        Some(it) => it,
+        None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+    };
+
+    let fixture_id = parse_id(&repair_item.fixture_id)?;
+    let fixture_position = match state.current_room().find_fixture(&fixture_id) {
+        Some(it) => it,
+        None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
+    };
+
+    if !fixture_position.fixture.fixture_type.can_repair_items() {
+        return Err(Error::FixtureCannotRepairItems(fixture_id.to_string()));
+    }
+
+    let durability = match &character_item.item.durability {
+        Some(it) => it,
+        None => return Err(Error::ItemCannotBeRepaired(item_id.to_string())),
+    };
+
+    if !durability.needs_repair() {
+        return Ok(Vec::new());
+    }
+
+    // The fixture only gives the player somewhere to work, the materials still cost.
+    let gold_spent = durability.repair_cost();
+    if player.gold < gold_spent {
+        return Err(Error::NotEnoughGold(gold_spent));
+    }
+
+    Ok(vec![Event::PlayerItemRepaired(PlayerItemRepaired {
+        item_id,
+        fixture_id,
+        gold_spent,
+    })])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        actions::{Action, RepairItem},
+        components::{
+            fixtures::FixtureType,
+            items::{CharacterItem, Durability, ItemType, LocationTag},
+            rooms::FixturePosition,
+        },
+        errors::Error,
+        events::apply_events,
+        game::Game,
+        generators::{
+            fixtures::get_generator, game::game_generator, generator::Generator,
+            items::item_generator_for_level, players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    #[test]
+    fn repairing_costs_gold_for_the_durability_missing() {
+        let mut state = game_generator(None, None, None).generate();
+        let mut player = player_generator(None, None, None).generate();
+        let table = get_generator(&FixtureType::Table, false).generate();
+        let fixture_id = table.id;
+        state
+            .current_room_mut()
+            .fixture_positions
+            .push(FixturePosition {
+                fixture: table,
+                position_descriptor: None,
+            });
+        let mut item =
+            item_generator_for_level(&ItemType::LongSword, false, 1, &Arc::default()).generate();
+        item.durability = Some(Durability {
+            current: 10,
+            max: 20,
+        });
+        let item_id = item.id;
+        player.character.add_item(CharacterItem {
+            item,
+            equipped_location: LocationTag::Packed,
+            at_the_ready: false,
+        });
+        let repair_item = RepairItem {
+            item_id: item_id.to_string(),
+            fixture_id: fixture_id.to_string(),
+        };
+
+        let result = handle(&repair_item, &state, &player);
+        assert!(matches!(result, Err(Error::NotEnoughGold(2))));
+
+        player.gold = 5;
+        let events = handle(&repair_item, &state, &player).unwrap();
+        let (_, player) = apply_events(&events, &state, &player);
+
+        assert_eq!(3, player.gold);
+        let durability = player
+            .character
+            .find_item(&item_id)
+            .unwrap()
+            .item
+            .durability;
+        assert_eq!(Some(20), durability.map(|durability| durability.current));
+    }
+
+    #[test]
+    fn repairs_are_only_offered_when_the_player_can_pay_for_them() {
+        let mut state = game_generator(None, None, None).generate();
+        let mut player = player_generator(None, None, None).generate();
+        let table = get_generator(&FixtureType::Table, false).generate();
+        state
+            .current_room_mut()
+            .fixture_positions
+            .push(FixturePosition {
+                fixture: table,
+                position_descriptor: None,
+            });
+        let mut item =
+            item_generator_for_level(&ItemType::LongSword, false, 1, &Arc::default()).generate();
+        item.durability = Some(Durability {
+            current: 10,
+            max: 20,
+        });
+        let item_id = item.id.to_string();
+        player.character.add_item(CharacterItem {
+            item,
+            equipped_location: LocationTag::Packed,
+            at_the_ready: false,
+        });
+        player.gold = 1;
+        let mut game = Game { state, player };
+
+        let offers_repair = |game: &Game| {
+            game.current_actions()
+                .iter()
+                .any(|action| matches!(action, Action::RepairItem(repair_item) if repair_item.item_id == item_id))
+        };
+        assert!(!offers_repair(&game));
+
+        game.player.gold = 2;
+        assert!(offers_repair(&game));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{
+    actions::SearchRoom,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, TrapDiscovered},
+};
+
+use super::helpers::discover_trap_succeeds;
+
+/// Looks for traps in the floor and on the exits of the current room. Traps on
+/// fixtures are found by inspecting the fixture instead.
+pub fn handle(
+    _search_room: &SearchRoom,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let room = state.current_room();
+
+    Ok(room
+        .traps
+        .iter()
+        .chain(room.exits.iter().filter_map(|exit| exit.trap.as_ref()))
+        .filter(|trap| trap.armed && !state.knows_trap(&trap.id))
+        .filter(|_| discover_trap_succeeds(player))
+        .map(|trap| Event::TrapDiscovered(TrapDiscovered { trap_id: trap.id }))
+        .collect())
+}
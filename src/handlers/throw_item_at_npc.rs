@@ -2,10 +2,12 @@ use crate::{
     actions::ThrowItemAtNpc,
     components::{games::GameState, PlayerCharacter},
     errors::Error,
-    events::{Event, PlayerItemRemoved},
+    events::{Event, NpcMissedInTheDark, PlayerItemRemoved},
     utils::ids::parse_id,
 };
 
+use super::helpers::misses_in_the_dark;
+
 pub fn handle(
     throw_item_at_npc: &ThrowItemAtNpc,
     state: &GameState,
@@ -31,6 +33,11 @@ pub fn handle(
                 for npc_position in room.npc_positions.iter() {
                     events.push(Event::NpcCoveredInOil(npc_position.npc.id));
                 }
+            } else if misses_in_the_dark(room) {
+                events.push(Event::NpcMissedInTheDark(NpcMissedInTheDark {
+                    attacker_id: player.id,
+                    npc_id,
+                }));
             } else {
                 events.push(Event::NpcCoveredInOil(npc_id));
             }
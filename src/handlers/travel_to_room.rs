@@ -0,0 +1,154 @@
+use crate::{
+    actions::{Action, ExitRoom, TravelToRoom},
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::Event,
+    utils::ids::parse_id,
+};
+
+use super::{handle_action, HandledAction};
+
+pub fn handle(
+    travel_to_room: &TravelToRoom,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<HandledAction, Error> {
+    let room_id = parse_id(&travel_to_room.room_id)?;
+    if !state.rooms_seen.contains(&room_id) {
+        return Err(Error::RoomNotFoundError(room_id.to_string()));
+    }
+
+    let path = match state.world.find_path(state.current_room_id, room_id, |id| {
+        state.rooms_seen.contains(id)
+    }) {
+        Some(it) => it,
+        None => return Err(Error::NoPathToRoom(room_id.to_string())),
+    };
+
+    let mut events: Vec<Event> = Vec::new();
+    let mut new_state = state.clone();
+    let mut new_player = player.clone();
+
+    for exit_id in path {
+        let mut handled = handle_action(
+            &Action::ExitRoom(ExitRoom {
+                exit_id: exit_id.to_string(),
+            }),
+            &new_state,
+            &new_player,
+        )?;
+        events.append(&mut handled.events);
+        new_state = handled.new_state;
+        new_player = handled.new_player;
+
+        if new_state.is_over()
+            || new_player.character.is_dead()
+            || new_state.current_room().first_alive_npc().is_some()
+        {
+            break;
+        }
+    }
+
+    Ok(HandledAction {
+        events,
+        new_player,
+        new_state,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::TravelToRoom,
+        components::games::GameState,
+        errors::Error,
+        generators::{
+            game::dungeon_game_generator, generator::Generator, players::player_generator,
+            DungeonGeneratorBuilder,
+        },
+    };
+
+    use super::handle;
+
+    /// A floor with nothing on it that could stop the player along the way.
+    fn quiet_floor() -> GameState {
+        let mut state = dungeon_game_generator(
+            DungeonGeneratorBuilder::new()
+                .num_rooms(6..=6)
+                .critical_path_length(4),
+            None,
+            None,
+            None,
+        )
+        .generate();
+        for room in state.world.rooms.iter_mut() {
+            room.npc_positions.clear();
+            room.traps.clear();
+            for exit in room.exits.iter_mut() {
+                exit.lock = None;
+                exit.trap = None;
+            }
+        }
+
+        state
+    }
+
+    #[test]
+    fn travelling_goes_through_every_room_on_the_way() {
+        let mut state = quiet_floor();
+        let player = player_generator(None, None, None).generate();
+        state.rooms_seen = state.world.rooms.iter().map(|room| room.id).collect();
+        let target = state
+            .world
+            .rooms
+            .iter()
+            .map(|room| room.id)
+            .find(|room_id| {
+                state
+                    .world
+                    .find_path(state.current_room_id, *room_id, |_| true)
+                    .is_some_and(|path| path.len() >= 2)
+            })
+            .unwrap();
+
+        let handled = handle(
+            &TravelToRoom {
+                room_id: target.to_string(),
+            },
+            &state,
+            &player,
+        )
+        .unwrap();
+
+        assert_eq!(target, handled.new_state.current_room_id);
+    }
+
+    #[test]
+    fn a_room_that_cannot_be_reached_through_seen_rooms_has_no_path() {
+        let mut state = quiet_floor();
+        let player = player_generator(None, None, None).generate();
+        let target = state
+            .world
+            .rooms
+            .iter()
+            .map(|room| room.id)
+            .find(|room_id| {
+                state
+                    .world
+                    .find_path(state.current_room_id, *room_id, |_| true)
+                    .is_some_and(|path| path.len() >= 2)
+            })
+            .unwrap();
+        state.rooms_seen = vec![state.current_room_id, target];
+
+        let result = handle(
+            &TravelToRoom {
+                room_id: target.to_string(),
+            },
+            &state,
+            &player,
+        );
+
+        assert!(matches!(result, Err(Error::NoPathToRoom(_))));
+    }
+}
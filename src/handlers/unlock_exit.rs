@@ -0,0 +1,40 @@
+use crate::{
+    actions::UnlockExit,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, ExitUnlocked, LockPickFailed},
+    utils::ids::parse_id,
+};
+
+use super::helpers::{unlock, Unlocking};
+
+pub fn handle(
+    unlock_exit: &UnlockExit,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let exit_id = parse_id(&unlock_exit.exit_id)?;
+    let lock = match state
+        .current_room()
+        .find_exit(&exit_id)
+        .map(|exit| exit.lock.as_ref())
+    {
+        Some(Some(it)) => it,
+        Some(None) => return Err(Error::NotLocked(exit_id.to_string())),
+        None => return Err(Error::ExitNotFoundError(exit_id.to_string())),
+    };
+
+    let event = match unlock(player, lock, unlock_exit.pick_lock, &exit_id)? {
+        Unlocking::WithKey(key_id) => Event::ExitUnlocked(ExitUnlocked {
+            exit_id,
+            key_id: Some(key_id),
+        }),
+        Unlocking::Picked => Event::ExitUnlocked(ExitUnlocked {
+            exit_id,
+            key_id: None,
+        }),
+        Unlocking::PickFailed => Event::LockPickFailed(LockPickFailed { target_id: exit_id }),
+    };
+
+    Ok(vec![event])
+}
@@ -0,0 +1,42 @@
+use crate::{
+    actions::UnlockFixture,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, FixtureUnlocked, LockPickFailed},
+    utils::ids::parse_id,
+};
+
+use super::helpers::{unlock, Unlocking};
+
+pub fn handle(
+    unlock_fixture: &UnlockFixture,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let fixture_id = parse_id(&unlock_fixture.fixture_id)?;
+    let lock = match state
+        .current_room()
+        .find_fixture(&fixture_id)
+        .map(|position| position.fixture.lock.as_ref())
+    {
+        Some(Some(it)) => it,
+        Some(None) => return Err(Error::NotLocked(fixture_id.to_string())),
+        None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
+    };
+
+    let event = match unlock(player, lock, unlock_fixture.pick_lock, &fixture_id)? {
+        Unlocking::WithKey(key_id) => Event::FixtureUnlocked(FixtureUnlocked {
+            fixture_id,
+            key_id: Some(key_id),
+        }),
+        Unlocking::Picked => Event::FixtureUnlocked(FixtureUnlocked {
+            fixture_id,
+            key_id: None,
+        }),
+        Unlocking::PickFailed => Event::LockPickFailed(LockPickFailed {
+            target_id: fixture_id,
+        }),
+    };
+
+    Ok(vec![event])
+}
@@ -19,11 +19,13 @@ pub fn handle(look_at_fixture: &LookAtFixture, state: &GameState) -> Result<Vec<
 
     let args = FixtureViewArgs {
         knows_has_hidden_compartment: knowledge.knows_has_hidden_compartment,
+        knows_has_trap: knowledge.knows_has_trap,
     };
 
     let view = fixture::view(
         &fixture_position.fixture,
         &args,
+        &state.item_view_args(),
         state.all_knowledge_unlocked,
     );
 
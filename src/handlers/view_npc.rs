@@ -20,9 +20,16 @@ pub fn handle(look_at_npc: &LookAtNpc, state: &GameState) -> Result<Vec<Event>,
         knows_health: knowledge.knows_health,
         knows_inventory: knowledge.knows_inventory,
         knows_packed_in_inventory: knowledge.knows_packed_in_inventory,
+        knows_attributes: knowledge.knows_attributes,
+        knows_spells: false,
     };
 
-    let view = non_player::view(npc, &args, state.all_knowledge_unlocked);
+    let view = non_player::view(
+        npc,
+        &args,
+        &state.item_view_args(),
+        state.all_knowledge_unlocked,
+    );
 
     Ok(vec![Event::NpcViewed(NpcViewed { npc_view: view })])
 }
@@ -4,8 +4,10 @@ pub mod errors;
 pub mod events;
 pub mod game;
 pub mod generators;
+pub mod graveyard;
 pub mod handlers;
 pub mod systems;
 pub mod utils;
 
 pub use game::Game;
+pub use graveyard::Graveyard;
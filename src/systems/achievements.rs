@@ -0,0 +1,276 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+#[cfg(feature = "json")]
+use crate::errors::Error;
+use crate::{
+    actions::Action,
+    components::{
+        achievements::{Achievement, AchievementRule, PlayerProfile},
+        games::Statistic,
+        items::ItemType,
+        Species,
+    },
+    events::{AchievementUnlocked, Event},
+    game::Game,
+    utils::ids::parse_id,
+};
+
+/// Goes through the achievements the profile hasn't unlocked yet and unlocks
+/// the ones whose rule was met by the turn that took `previous` to `game`.
+pub fn check(
+    achievements: &[Achievement],
+    profile: &PlayerProfile,
+    action: &Action,
+    events: &[Event],
+    previous: &Game,
+    game: &Game,
+) -> Vec<Event> {
+    achievements
+        .iter()
+        .filter(|achievement| !profile.has_unlocked(&achievement.id))
+        .filter(|achievement| rule_met(&achievement.rule, action, events, previous, game))
+        .map(|achievement| {
+            Event::AchievementUnlocked(AchievementUnlocked {
+                achievement_id: achievement.id.clone(),
+                name: achievement.name.clone(),
+                unlocked_at: Utc::now(),
+            })
+        })
+        .collect()
+}
+
+/// The achievements that come with the crate.
+pub fn default_achievements() -> Vec<Achievement> {
+    vec![
+        Achievement {
+            id: "ogre_flasked".to_string(),
+            name: "Bottled Up".to_string(),
+            description: "Kill an ogre with a thrown flask.".to_string(),
+            rule: AchievementRule::KillNpc {
+                species: Some(Species::Ogre),
+                boss: false,
+                thrown_item_type: Some(ItemType::Flask),
+                spell_name: None,
+            },
+        },
+        Achievement {
+            id: "untouched".to_string(),
+            name: "Untouched".to_string(),
+            description: "Clear a room without taking any damage.".to_string(),
+            rule: AchievementRule::ClearRoomUnharmed,
+        },
+        Achievement {
+            id: "boss_slayer".to_string(),
+            name: "Boss Slayer".to_string(),
+            description: "Kill a boss.".to_string(),
+            rule: AchievementRule::KillNpc {
+                species: None,
+                boss: true,
+                thrown_item_type: None,
+                spell_name: None,
+            },
+        },
+        Achievement {
+            id: "explorer".to_string(),
+            name: "Explorer".to_string(),
+            description: "Explore 50 rooms in a single run.".to_string(),
+            rule: AchievementRule::ReachStatistic {
+                statistic: Statistic::RoomsExplored,
+                value: 50,
+            },
+        },
+        Achievement {
+            id: "victorious".to_string(),
+            name: "Victorious".to_string(),
+            description: "Win a game.".to_string(),
+            rule: AchievementRule::WinGame { victory: None },
+        },
+    ]
+}
+
+#[cfg(feature = "json")]
+pub fn achievements_from_json(text: &str) -> Result<Vec<Achievement>, Error> {
+    serde_json::from_str(text).map_err(|e| Error::InvalidAchievements(e.to_string()))
+}
+
+fn rule_met(
+    rule: &AchievementRule,
+    action: &Action,
+    events: &[Event],
+    previous: &Game,
+    game: &Game,
+) -> bool {
+    match rule {
+        AchievementRule::KillNpc {
+            species,
+            boss,
+            thrown_item_type,
+            spell_name,
+        } => {
+            if let Some(item_type) = thrown_item_type {
+                let thrown = match action {
+                    Action::ThrowItemAtNpc(throw_item_at_npc) => {
+                        parse_id(&throw_item_at_npc.item_id)
+                            .ok()
+                            .and_then(|item_id| previous.player.character.find_item(&item_id))
+                            .map(|character_item| character_item.item.item_type)
+                    }
+                    _ => None,
+                };
+                if thrown.as_ref() != Some(item_type) {
+                    return false;
+                }
+            }
+
+            if let Some(spell_name) = spell_name {
+                let cast = match action {
+                    Action::CastSpellOnNpc(cast_spell_on_npc) => {
+                        parse_id(&cast_spell_on_npc.spell_id)
+                            .ok()
+                            .and_then(|spell_id| previous.player.character.find_spell(&spell_id))
+                            .map(|learned_spell| learned_spell.spell.name)
+                    }
+                    _ => None,
+                };
+                if cast.as_ref() != Some(spell_name) {
+                    return false;
+                }
+            }
+
+            killed_npc_ids(events).any(|npc_id| {
+                game.state.find_npc(&npc_id).is_some_and(|npc| {
+                    species.is_none_or(|species| npc.character.species == species)
+                        && (!boss || npc.is_boss())
+                })
+            })
+        }
+        AchievementRule::ClearRoomUnharmed => {
+            killed_npc_ids(events).next().is_some()
+                && game.state.current_room().first_alive_npc().is_none()
+                && game.state.damage_taken_in_room == 0
+        }
+        AchievementRule::ReachStatistic { statistic, value } => {
+            game.state.player_stats(&game.player.id).get(statistic) >= *value
+        }
+        AchievementRule::WinGame { victory } => events.iter().any(|event| match event {
+            Event::GameEnded(game_ended) => match victory {
+                Some(condition) => game_ended.outcome.victory.as_ref() == Some(condition),
+                None => game_ended.outcome.is_victory(),
+            },
+            _ => false,
+        }),
+    }
+}
+
+fn killed_npc_ids(events: &[Event]) -> impl Iterator<Item = Uuid> + '_ {
+    events.iter().filter_map(|event| match event {
+        Event::PlayerKilledNpc(player_killed_npc) => Some(player_killed_npc.npc_id),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::{Action, SearchRoom},
+        components::{
+            achievements::{Achievement, AchievementRule, PlayerProfile},
+            games::Statistic,
+        },
+        events::{apply_events, apply_profile_events, Event, PlayerKilledNpc},
+        game::Game,
+        generators::{game::game_generator, generator::Generator, players::player_generator},
+    };
+
+    use super::check;
+
+    #[test]
+    fn achievements_are_only_unlocked_once() {
+        let previous = Game {
+            state: game_generator(None, None, None).generate(),
+            player: player_generator(None, None, None).generate(),
+        };
+        let mut game = Game {
+            state: previous.state.clone(),
+            player: previous.player.clone(),
+        };
+        game.state.player_stats_mut(&game.player.id).turns_taken = 1;
+        let achievements = vec![Achievement {
+            id: "first_turn".to_string(),
+            name: "First Turn".to_string(),
+            description: "Take a turn.".to_string(),
+            rule: AchievementRule::ReachStatistic {
+                statistic: Statistic::TurnsTaken,
+                value: 1,
+            },
+        }];
+        let action = Action::SearchRoom(SearchRoom {});
+
+        let profile = PlayerProfile::default();
+        let events = check(&achievements, &profile, &action, &[], &previous, &game);
+        assert_eq!(1, events.len());
+
+        let profile = apply_profile_events(&events, &profile);
+        assert!(profile.has_unlocked("first_turn"));
+        assert!(check(&achievements, &profile, &action, &[], &previous, &game).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn achievements_can_be_read_from_json() {
+        use super::achievements_from_json;
+
+        let text = r#"[{
+            "id": "ogre_slayer",
+            "name": "Ogre Slayer",
+            "description": "Kill an ogre with a thrown flask.",
+            "rule": { "rule_type": "kill_npc", "species": "ogre", "thrown_item_type": "flask" }
+        }]"#;
+
+        let achievements = achievements_from_json(text).unwrap();
+        assert!(matches!(
+            achievements[0].rule,
+            AchievementRule::KillNpc { boss: false, .. }
+        ));
+    }
+
+    #[test]
+    fn poison_damage_spoils_clearing_a_room_unharmed() {
+        let mut state = game_generator(None, None, None).generate();
+        state.current_room_mut().npc_positions.clear();
+        let player = player_generator(None, None, None).generate();
+        let previous = Game {
+            state: state.clone(),
+            player: player.clone(),
+        };
+        let events = vec![
+            Event::PlayerDamagedByPoison(1),
+            Event::PlayerKilledNpc(PlayerKilledNpc {
+                killer_id: player.id,
+                npc_id: Uuid::new_v4(),
+            }),
+        ];
+        let (state, player) = apply_events(&events, &state, &player);
+        let game = Game { state, player };
+        let achievements = vec![Achievement {
+            id: "untouched".to_string(),
+            name: "Untouched".to_string(),
+            description: "Clear a room without taking any damage.".to_string(),
+            rule: AchievementRule::ClearRoomUnharmed,
+        }];
+
+        let unlocked = check(
+            &achievements,
+            &PlayerProfile::default(),
+            &Action::SearchRoom(SearchRoom {}),
+            &events,
+            &previous,
+            &game,
+        );
+
+        assert!(unlocked.is_empty());
+    }
+}
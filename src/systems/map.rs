@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::components::{
+    games::GameState,
+    rooms::{Coordinates, Direction, Exit, Room, RoomType},
+};
+
+const UNEXPLORED: char = '?';
+const CURRENT_ROOM: char = '@';
+const CELL_WIDTH: i32 = 4;
+const CELL_HEIGHT: i32 = 2;
+
+/// Draws the rooms the player has seen on the floor they are currently on.
+/// Rooms are drawn as `[x]` with a symbol for their type, the current room
+/// is `[@]`, and exits that lead somewhere unseen are drawn as `?`.
+pub fn ascii_map(state: &GameState) -> String {
+    let depth = match state
+        .world
+        .rooms
+        .iter()
+        .find(|room| room.id == state.current_room_id)
+    {
+        Some(room) => room.depth,
+        None => return String::new(),
+    };
+
+    let rooms: HashMap<Coordinates, &Room> = seen_rooms(state)
+        .into_iter()
+        .filter(|room| room.depth == depth)
+        .map(|room| (room.coordinates, room))
+        .collect();
+    if rooms.is_empty() {
+        return String::new();
+    }
+
+    let min_x = rooms.keys().map(|c| c.x).min().unwrap_or_default() - 1;
+    let max_x = rooms.keys().map(|c| c.x).max().unwrap_or_default() + 1;
+    let min_y = rooms.keys().map(|c| c.y).min().unwrap_or_default() - 1;
+    let max_y = rooms.keys().map(|c| c.y).max().unwrap_or_default() + 1;
+
+    // Every cell is four characters wide and two lines high, leaving room for the
+    // exits between cells. The border of empty cells keeps exits out of the map visible.
+    let width = ((max_x - min_x + 1) * CELL_WIDTH) as usize;
+    let height = ((max_y - min_y + 1) * CELL_HEIGHT) as usize;
+    let mut canvas = vec![vec![' '; width]; height];
+
+    for room in rooms.values() {
+        let column = ((room.coordinates.x - min_x) * CELL_WIDTH) as usize;
+        let row = ((room.coordinates.y - min_y) * CELL_HEIGHT) as usize;
+        let symbol = if room.id == state.current_room_id {
+            CURRENT_ROOM
+        } else {
+            room_symbol(&room.room_type)
+        };
+        canvas[row][column] = '[';
+        canvas[row][column + 1] = symbol;
+        canvas[row][column + 2] = ']';
+
+        for (direction, row, column, explored) in [
+            (Direction::North, row - 1, column + 1, '|'),
+            (Direction::East, row, column + 3, '-'),
+            (Direction::South, row + 1, column + 1, '|'),
+            (Direction::West, row, column - 1, '-'),
+        ] {
+            if let Some(symbol) = exit_symbol(state, room, &direction, explored) {
+                canvas[row][column] = symbol;
+            }
+        }
+    }
+
+    let mut map: Vec<String> = canvas
+        .into_iter()
+        .map(|line| line.into_iter().collect::<String>().trim_end().to_string())
+        .skip_while(|line| line.is_empty())
+        .collect();
+    while map.last().is_some_and(|line| line.is_empty()) {
+        map.pop();
+    }
+
+    // Exits through the floor or ceiling don't fit on a flat map.
+    let mut legend: Vec<String> = rooms
+        .values()
+        .filter_map(|room| {
+            let vertical: Vec<&str> = room
+                .exits
+                .iter()
+                .filter_map(|exit| match exit.direction {
+                    Some(Direction::Up) => Some("up"),
+                    Some(Direction::Down) => Some("down"),
+                    _ => None,
+                })
+                .collect();
+            if vertical.is_empty() {
+                None
+            } else {
+                Some(format!(
+                    "({}, {}) {}: {}",
+                    room.coordinates.x,
+                    room.coordinates.y,
+                    room_label(room),
+                    vertical.join(", ")
+                ))
+            }
+        })
+        .collect();
+    legend.sort();
+
+    map.push(format!("Depth {}", depth));
+    map.extend(legend);
+    map.join("\n")
+}
+
+/// Every room the player has seen on every floor, as a Graphviz graph. Exits
+/// that lead to rooms the player hasn't seen are drawn as dashed edges to a
+/// point, and the current room gets a double border.
+pub fn graphviz_map(state: &GameState) -> String {
+    let rooms = seen_rooms(state);
+    let mut lines = vec!["graph world {".to_string()];
+
+    for room in &rooms {
+        let peripheries = if room.id == state.current_room_id {
+            2
+        } else {
+            1
+        };
+        lines.push(format!(
+            "    \"{}\" [label=\"{}\\ndepth {} ({}, {})\", peripheries={}];",
+            room.id,
+            escape(&room_label(room)),
+            room.depth,
+            room.coordinates.x,
+            room.coordinates.y,
+            peripheries
+        ));
+    }
+
+    for exit_map in &state.world.exit_graph {
+        let seen: Vec<&Room> = [exit_map.left_room_id, exit_map.right_room_id]
+            .iter()
+            .flatten()
+            .filter_map(|room_id| rooms.iter().find(|room| room.id.eq(room_id)).copied())
+            .collect();
+        let exit = match seen
+            .first()
+            .and_then(|room| room.find_exit(&exit_map.exit_id))
+        {
+            Some(it) => it,
+            None => continue,
+        };
+
+        match seen.as_slice() {
+            [left, right] => lines.push(format!(
+                "    \"{}\" -- \"{}\" [label=\"{:?}\"];",
+                left.id, right.id, exit.exit_type
+            )),
+            [room] => {
+                lines.push(format!(
+                    "    \"{}\" [label=\"{}\", shape=point];",
+                    exit.id, UNEXPLORED
+                ));
+                lines.push(format!(
+                    "    \"{}\" -- \"{}\" [label=\"{:?}\", style=dashed];",
+                    room.id, exit.id, exit.exit_type
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+fn seen_rooms(state: &GameState) -> Vec<&Room> {
+    state
+        .world
+        .rooms
+        .iter()
+        .filter(|room| state.rooms_seen.contains(&room.id) || room.id == state.current_room_id)
+        .collect()
+}
+
+fn is_unexplored(state: &GameState, room: &Room, exit: &Exit) -> bool {
+    state
+        .world
+        .exit_graph
+        .iter()
+        .find(|exit_map| exit_map.exit_id.eq(&exit.id))
+        .and_then(|exit_map| exit_map.other_room_id(room.id))
+        .is_none_or(|other_id: Uuid| !state.rooms_seen.contains(&other_id))
+}
+
+fn exit_symbol(
+    state: &GameState,
+    room: &Room,
+    direction: &Direction,
+    explored: char,
+) -> Option<char> {
+    let exits: Vec<&Exit> = room
+        .exits
+        .iter()
+        .filter(|exit| exit.direction.as_ref() == Some(direction))
+        .collect();
+
+    if exits.is_empty() {
+        None
+    } else if exits.iter().any(|exit| is_unexplored(state, room, exit)) {
+        Some(UNEXPLORED)
+    } else {
+        Some(explored)
+    }
+}
+
+fn room_label(room: &Room) -> String {
+    match &room.name {
+        Some(name) => format!("{:?} {}", room.room_type, name),
+        None => format!("{:?}", room.room_type),
+    }
+}
+
+fn room_symbol(room_type: &RoomType) -> char {
+    match *room_type {
+        RoomType::Cave => 'c',
+        RoomType::Cavern => 'C',
+        RoomType::Cemetery => 'g',
+        RoomType::Crypt => 'y',
+        RoomType::EntryWay => 'E',
+        RoomType::Mausoleum => 'M',
+        RoomType::PrisonCell => 'P',
+        RoomType::Room => 'R',
+        RoomType::TavernHall => 'T',
+        RoomType::TempleHall => 'H',
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::{
+        game::dungeon_game_generator, generator::Generator, DungeonGeneratorBuilder,
+    };
+
+    use super::{ascii_map, graphviz_map};
+
+    #[test]
+    fn only_the_entry_room_is_drawn_at_the_start() {
+        let game_state = dungeon_game_generator(
+            DungeonGeneratorBuilder::new().num_rooms(6..=6),
+            None,
+            None,
+            None,
+        )
+        .generate();
+
+        let ascii = ascii_map(&game_state);
+        assert_eq!(1, ascii.matches('[').count());
+        assert!(ascii.contains("[@]"));
+        assert!(ascii.contains('?'));
+
+        let dot = graphviz_map(&game_state);
+        assert_eq!(1, dot.matches("peripheries=").count());
+        assert!(dot.contains("style=dashed"));
+    }
+}
@@ -1 +1,3 @@
+pub mod achievements;
+pub mod map;
 pub mod view;
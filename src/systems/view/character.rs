@@ -1,19 +1,36 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
+    items::ItemViewArgs,
     spells::{LearnedSpellView, SpellMemoryView, SpellView},
     EffectsView, StatsView, {Character, CharacterView, CharacterViewArgs},
 };
 
-pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) -> CharacterView {
+pub fn view(
+    character: &Character,
+    args: &CharacterViewArgs,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
+    knows_all: bool,
+) -> CharacterView {
     let (health, health_known) = if args.knows_health || knows_all {
         (Some(character.stats.health.clone()), true)
     } else {
         (None, false)
     };
 
+    let (attributes, attributes_known) = if args.knows_attributes || knows_all {
+        (Some(character.stats.attributes.clone()), true)
+    } else {
+        (None, false)
+    };
+
     let (inventory, inventory_known) = if args.knows_inventory || knows_all {
         (
             Some(super::inventory::view(
                 &character.inventory,
+                item_args,
                 args.knows_packed_in_inventory,
                 knows_all,
             )),
@@ -23,7 +40,7 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
         (None, false)
     };
 
-    let (spell_memory, spell_memory_known) = if knows_all {
+    let (spell_memory, spell_memory_known) = if args.knows_spells || knows_all {
         (
             Some(SpellMemoryView {
                 spells: character
@@ -58,6 +75,8 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
             health,
             health_known,
             height: character.stats.height,
+            attributes,
+            attributes_known,
         },
         species: character.species,
         life_modifier: character.life_modifier,
@@ -1,10 +1,23 @@
-use crate::components::items::{CharacterItem, CharacterItemView};
+use std::collections::HashMap;
 
-pub fn view(character_item: &CharacterItem, knows_all: bool) -> CharacterItemView {
+use uuid::Uuid;
+
+use crate::components::items::{CharacterItem, CharacterItemView, ItemViewArgs};
+
+pub fn view(
+    character_item: &CharacterItem,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
+    knows_all: bool,
+) -> CharacterItemView {
     let full_item_hidden = character_item.equipped_location.hides_full_item();
 
+    let args = item_args
+        .get(&character_item.item.id)
+        .cloned()
+        .unwrap_or_default();
+
     CharacterItemView {
-        item: super::item::view(&character_item.item, !full_item_hidden, knows_all),
+        item: super::item::view(&character_item.item, &args, !full_item_hidden, knows_all),
         at_the_ready: character_item.at_the_ready,
         equipped_location: character_item.equipped_location,
     }
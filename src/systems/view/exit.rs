@@ -1,6 +1,6 @@
 use crate::components::rooms::{Exit, ExitView};
 
-pub fn view(exit: &Exit, has_visited_connected_room: bool) -> ExitView {
+pub fn view(exit: &Exit, has_visited_connected_room: bool, knows_trap: bool) -> ExitView {
     ExitView {
         has_visited_connected_room,
         id: exit.id.to_string(),
@@ -9,5 +9,12 @@ pub fn view(exit: &Exit, has_visited_connected_room: bool) -> ExitView {
         material: exit.material,
         descriptors: exit.descriptors.to_vec(),
         size: exit.size,
+        direction: exit.direction,
+        is_locked: exit.is_locked(),
+        trap: exit
+            .trap
+            .as_ref()
+            .filter(|trap| knows_trap && trap.armed)
+            .map(super::trap::view),
     }
 }
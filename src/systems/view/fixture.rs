@@ -1,16 +1,33 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
     fixtures::{Fixture, FixtureView, FixtureViewArgs},
-    items::FixtureItemView,
+    items::{FixtureItemView, ItemViewArgs},
 };
 
-pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> FixtureView {
+pub fn view(
+    fixture: &Fixture,
+    args: &FixtureViewArgs,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
+    knows_all: bool,
+) -> FixtureView {
     let items: Vec<FixtureItemView> = fixture
         .items
         .iter()
         .filter_map(|fixture_item| {
             if fixture_item.is_inside && fixture.open {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(
+                        &fixture_item.item,
+                        &item_args
+                            .get(&fixture_item.item.id)
+                            .cloned()
+                            .unwrap_or_default(),
+                        true,
+                        knows_all,
+                    ),
                     is_in_hidden_compartment: Some(false),
                     is_in_hidden_compartment_known: true,
                     is_inside: true,
@@ -19,7 +36,15 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
                 None
             } else if fixture_item.is_in_hidden_compartment && fixture.hidden_compartment_open {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(
+                        &fixture_item.item,
+                        &item_args
+                            .get(&fixture_item.item.id)
+                            .cloned()
+                            .unwrap_or_default(),
+                        true,
+                        knows_all,
+                    ),
                     is_in_hidden_compartment: Some(true),
                     is_in_hidden_compartment_known: true,
                     is_inside: false,
@@ -28,7 +53,15 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
                 None
             } else {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(
+                        &fixture_item.item,
+                        &item_args
+                            .get(&fixture_item.item.id)
+                            .cloned()
+                            .unwrap_or_default(),
+                        true,
+                        knows_all,
+                    ),
                     is_in_hidden_compartment: Some(false),
                     is_in_hidden_compartment_known: true,
                     is_inside: false,
@@ -59,5 +92,11 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
         open: fixture.open,
         can_be_opened: fixture.can_be_opened,
         hidden_compartment_open,
+        is_locked: fixture.is_locked(),
+        trap: fixture
+            .trap
+            .as_ref()
+            .filter(|trap| (args.knows_has_trap || knows_all) && trap.armed)
+            .map(super::trap::view),
     }
 }
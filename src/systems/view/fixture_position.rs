@@ -4,12 +4,14 @@ use uuid::Uuid;
 
 use crate::components::{
     fixtures::FixtureViewArgs,
+    items::ItemViewArgs,
     rooms::{FixturePosition, FixturePositionView},
 };
 
 pub fn view(
     fixture_position: &FixturePosition,
     fixture_view_args: &HashMap<Uuid, FixtureViewArgs>,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
     knows_all: bool,
 ) -> FixturePositionView {
     let args = fixture_view_args
@@ -22,7 +24,7 @@ pub fn view(
                 FixtureViewArgs::default()
             }
         });
-    let fixture = super::fixture::view(&fixture_position.fixture, &args, knows_all);
+    let fixture = super::fixture::view(&fixture_position.fixture, &args, item_args, knows_all);
 
     FixturePositionView {
         fixture,
@@ -0,0 +1,15 @@
+use crate::components::games::{GameOutcome, GameOutcomeView};
+
+pub fn view(outcome: &GameOutcome) -> GameOutcomeView {
+    GameOutcomeView {
+        victory: outcome.victory,
+        statistics: outcome.statistics.clone(),
+        score: outcome.statistics.score(),
+        rooms_seen: outcome.rooms_seen,
+        gold: outcome.gold,
+        danger_level: outcome.danger_level,
+        deepest_depth: outcome.deepest_depth,
+        killer_id: outcome.killer_id.map(|id| id.to_string()),
+        killer_name: outcome.killer_name.clone(),
+    }
+}
@@ -40,6 +40,11 @@ pub fn view(game_state: &GameState) -> GameStateView {
         .iter()
         .map(|(fixture_id, knowledge)| (fixture_id.to_string(), knowledge.clone()));
 
+    let player_item_knowledge = game_state
+        .player_item_knowledge
+        .iter()
+        .map(|(item_id, knowledge)| (item_id.to_string(), knowledge.clone()));
+
     let player_statistics = game_state
         .player_statistics
         .iter()
@@ -48,13 +53,21 @@ pub fn view(game_state: &GameState) -> GameStateView {
     GameStateView {
         id: game_state.id.to_string(),
         name: game_state.name.clone(),
-        world: WorldView { rooms, exit_graph },
+        world: WorldView {
+            rooms,
+            exit_graph,
+            deepest_depth: game_state.world.deepest_depth,
+        },
         current_room_id: game_state.current_room_id.to_string(),
         rooms_seen,
         all_knowledge_unlocked: game_state.all_knowledge_unlocked,
         player_npc_knowledge: HashMap::from_iter(player_npc_knowledge),
         player_fixture_knowledge: HashMap::from_iter(player_fixture_knowledge),
+        player_item_knowledge: HashMap::from_iter(player_item_knowledge),
         player_statistics: HashMap::from_iter(player_statistics),
         danger_level: game_state.danger_level,
+        quests: game_state.quests.iter().map(super::quest::view).collect(),
+        victory_conditions: game_state.victory_conditions.clone(),
+        outcome: game_state.outcome.as_ref().map(super::game_outcome::view),
     }
 }
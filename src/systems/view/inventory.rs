@@ -1,11 +1,20 @@
-use crate::components::{Inventory, InventoryView};
+use std::collections::HashMap;
 
-pub fn view(inventory: &Inventory, knows_packed: bool, knows_all: bool) -> InventoryView {
+use uuid::Uuid;
+
+use crate::components::{items::ItemViewArgs, Inventory, InventoryView};
+
+pub fn view(
+    inventory: &Inventory,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
+    knows_packed: bool,
+    knows_all: bool,
+) -> InventoryView {
     let equipped_items = inventory
         .equipment
         .iter()
         .filter(|character_item| character_item.is_at_the_ready())
-        .map(|character_item| super::character_item::view(character_item, true));
+        .map(|character_item| super::character_item::view(character_item, item_args, knows_all));
 
     let packed_items = inventory
         .equipment
@@ -13,7 +22,11 @@ pub fn view(inventory: &Inventory, knows_packed: bool, knows_all: bool) -> Inven
         .filter(|character_item| character_item.is_packed())
         .filter_map(|character_item| {
             if knows_packed || knows_all {
-                Some(super::character_item::view(character_item, true))
+                Some(super::character_item::view(
+                    character_item,
+                    item_args,
+                    knows_all,
+                ))
             } else {
                 None
             }
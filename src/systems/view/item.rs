@@ -1,8 +1,9 @@
 use crate::components::items::{
-    ConsumableView, ThrowableView, {Item, ItemView},
+    ConsumableView, ThrowableView, {Item, ItemView, ItemViewArgs},
 };
 
-pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
+pub fn view(item: &Item, args: &ItemViewArgs, sees_full_item: bool, knows_all: bool) -> ItemView {
+    let sees_full_item = sees_full_item || args.carried_by_player;
     let (descriptors, descriptors_known) = if sees_full_item || knows_all {
         (item.descriptors.clone(), true)
     } else {
@@ -15,19 +16,19 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
         (None, false)
     };
 
-    let (attack, attack_known) = if knows_all {
+    let (attack, attack_known) = if args.knows_attack || args.carried_by_player || knows_all {
         (item.attack.clone(), true)
     } else {
         (None, false)
     };
 
-    let (defense, defense_known) = if knows_all {
+    let (defense, defense_known) = if args.knows_defense || args.carried_by_player || knows_all {
         (item.defense.clone(), true)
     } else {
         (None, false)
     };
 
-    let (consumable, knows_consumable) = if knows_all {
+    let (consumable, knows_consumable) = if args.knows_consumable || knows_all {
         if let Some(c) = &item.consumable {
             (
                 Some(ConsumableView {
@@ -45,6 +46,12 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
         (None, false)
     };
 
+    let (affixes, affixes_known) = if args.knows_affixes || knows_all {
+        (item.affixes.clone(), true)
+    } else {
+        (Vec::new(), false)
+    };
+
     let throwable = item.throwable.as_ref().map(|throwable| ThrowableView {
         uses: throwable.uses,
         effect: throwable.effect.clone(),
@@ -67,5 +74,9 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
         consumable,
         knows_consumable,
         throwable,
+        weight: item.weight(),
+        durability: item.durability.clone(),
+        affixes,
+        affixes_known,
     }
 }
@@ -3,10 +3,13 @@ pub mod character_item;
 pub mod exit;
 pub mod fixture;
 pub mod fixture_position;
+pub mod game_outcome;
 pub mod game_state;
 pub mod inventory;
 pub mod item;
 pub mod non_player;
 pub mod npc_position;
 pub mod player;
+pub mod quest;
 pub mod room;
+pub mod trap;
@@ -1,10 +1,16 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
+    items::ItemViewArgs,
     CharacterViewArgs, {NonPlayer, NonPlayerView},
 };
 
 pub fn view(
     non_player: &NonPlayer,
     character_args: &CharacterViewArgs,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
     knows_all: bool,
 ) -> NonPlayerView {
     let can_be_looted =
@@ -14,6 +20,13 @@ pub fn view(
         id: non_player.id.to_string(),
         can_be_looted,
         name: non_player.name.clone(),
-        character: super::character::view(&non_player.character, character_args, knows_all),
+        rank: non_player.rank,
+        title: non_player.title.clone(),
+        character: super::character::view(
+            &non_player.character,
+            character_args,
+            item_args,
+            knows_all,
+        ),
     }
 }
@@ -1,14 +1,38 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
+    items::ItemViewArgs,
     CharacterViewArgs, {PlayerCharacter, PlayerCharacterView},
 };
 
-pub fn check(player_character: &PlayerCharacter) -> PlayerCharacterView {
+pub fn check(
+    player_character: &PlayerCharacter,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
+) -> PlayerCharacterView {
     let args = CharacterViewArgs {
         knows_health: true,
         knows_inventory: true,
         knows_packed_in_inventory: true,
+        knows_attributes: true,
+        knows_spells: true,
     };
-    let character = super::character::view(&player_character.character, &args, true);
+    let item_args: HashMap<Uuid, ItemViewArgs> = player_character
+        .character
+        .inventory
+        .equipment
+        .iter()
+        .map(|character_item| {
+            let mut item_args = item_args
+                .get(&character_item.item.id)
+                .cloned()
+                .unwrap_or_default();
+            item_args.carried_by_player = true;
+            (character_item.item.id, item_args)
+        })
+        .collect();
+    let character = super::character::view(&player_character.character, &args, &item_args, false);
 
     PlayerCharacterView {
         character,
@@ -17,3 +41,106 @@ pub fn check(player_character: &PlayerCharacter) -> PlayerCharacterView {
         name: player_character.name.clone(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use crate::{
+        actions::{Action, LootNpc},
+        components::{
+            items::{
+                Affix, CharacterItem, Consumable, ConsumableEffect, ConsumableEffectName, ItemType,
+                LearnSpellEffect, LocationTag,
+            },
+            rooms::NpcPosition,
+            spells::SpellName,
+        },
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            non_players::NonPlayerGeneratorBuilder, players::player_generator,
+        },
+        handlers::handle_action,
+    };
+
+    use super::check;
+
+    #[test]
+    fn a_looted_scroll_stays_unidentified() {
+        let mut state = game_generator(None, None, None).generate();
+        state.current_room_mut().npc_positions.clear();
+        let player = player_generator(None, None, None).generate();
+        let mut scroll =
+            item_generator_for_level(&ItemType::Scroll, false, 1, &Arc::default()).generate();
+        scroll.consumable = Some(Consumable {
+            uses: 1,
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::LearnSpell,
+                learn_spell_effect: Some(LearnSpellEffect {
+                    spell_name: SpellName::Heal,
+                    spell_attack: None,
+                    spell_defense: None,
+                    spell_uses: 1,
+                }),
+                healing_effect: None,
+            },
+        });
+        let scroll_id = scroll.id;
+        let mut npc = NonPlayerGeneratorBuilder::new().build().generate();
+        npc.character.inventory.equipment.clear();
+        npc.character.add_item(CharacterItem {
+            item: scroll,
+            equipped_location: LocationTag::Packed,
+            at_the_ready: false,
+        });
+        npc.kill();
+        let npc_id = npc.id;
+        state.current_room_mut().npc_positions.push(NpcPosition {
+            npc,
+            position_descriptor: None,
+        });
+
+        let handled = handle_action(
+            &Action::LootNpc(LootNpc {
+                npc_id: npc_id.to_string(),
+                item_ids: vec![scroll_id.to_string()],
+            }),
+            &state,
+            &player,
+        )
+        .unwrap();
+        let view = check(&handled.new_player, &handled.new_state.item_view_args());
+
+        let scroll_view = view
+            .character
+            .inventory
+            .unwrap()
+            .equipment
+            .into_iter()
+            .find(|character_item| character_item.item.id == scroll_id.to_string())
+            .unwrap();
+        assert!(!scroll_view.item.knows_consumable);
+        assert!(scroll_view.item.descriptors_known);
+    }
+
+    #[test]
+    fn a_curse_on_the_players_own_gear_stays_hidden_until_inspected() {
+        let mut player = player_generator(None, None, None).generate();
+        let character_item = player.character.inventory.equipment.first_mut().unwrap();
+        character_item.item.affixes.push(Affix::Binding);
+        let item_id = character_item.item.id;
+
+        let view = check(&player, &HashMap::new());
+        let item_view = view
+            .character
+            .inventory
+            .unwrap()
+            .equipment
+            .into_iter()
+            .find(|character_item| character_item.item.id == item_id.to_string())
+            .unwrap();
+
+        assert!(!item_view.item.affixes_known);
+        assert!(item_view.item.affixes.is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::components::quests::{Objective, Quest, QuestView, Reward};
+
+pub fn view(quest: &Quest) -> QuestView {
+    let description = match &quest.objective {
+        Objective::KillSpecies { species, count } => format!("Kill {} {:?}", count, species),
+        Objective::RetrieveItem { item_name, .. } => format!("Retrieve {}", item_name),
+        Objective::ReachDepth { depth } => format!("Reach depth {}", depth),
+        Objective::EscortNpc { npc, depth } => format!(
+            "Escort {} the {:?} to depth {}",
+            npc.name.clone().unwrap_or_default(),
+            npc.character.species,
+            depth
+        ),
+    };
+
+    let reward = match &quest.reward {
+        Reward::Gold(gold) => format!("{} gold", gold),
+        Reward::Item(item) => match &item.name {
+            Some(name) => name.clone(),
+            None => format!("{:?}", item.item_type),
+        },
+        Reward::Spell(spell) => format!("{:?}", spell.name),
+    };
+
+    QuestView {
+        id: quest.id.to_string(),
+        description,
+        progress: quest.progress,
+        goal: quest.objective.goal(),
+        completed: quest.completed,
+        failed: quest.failed,
+        reward,
+    }
+}
@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::components::{
     fixtures::FixtureViewArgs,
+    items::ItemViewArgs,
     rooms::{ExitView, FixturePositionView, NpcPositionView, Room, RoomView},
     NonPlayerViewArgs,
 };
@@ -12,21 +13,25 @@ pub fn view(
     room: &Room,
     non_player_args: HashMap<Uuid, NonPlayerViewArgs>,
     fixture_args: HashMap<Uuid, FixtureViewArgs>,
+    item_args: HashMap<Uuid, ItemViewArgs>,
     exit_visitations: HashMap<Uuid, bool>,
+    known_trap_ids: &[Uuid],
     knows_all: bool,
 ) -> RoomView {
     let fixture_positions: Vec<FixturePositionView> = room
         .fixture_positions
         .iter()
         .map(|fixture_position| {
-            super::fixture_position::view(fixture_position, &fixture_args, knows_all)
+            super::fixture_position::view(fixture_position, &fixture_args, &item_args, knows_all)
         })
         .into_iter()
         .collect();
     let npc_positions: Vec<NpcPositionView> = room
         .npc_positions
         .iter()
-        .map(|npc_position| super::npc_position::view(npc_position, &non_player_args, knows_all))
+        .map(|npc_position| {
+            super::npc_position::view(npc_position, &non_player_args, &item_args, knows_all)
+        })
         .into_iter()
         .collect();
 
@@ -36,7 +41,12 @@ pub fn view(
         .map(|exit| {
             let has_visited_connected_room =
                 exit_visitations.get(&exit.id).cloned().unwrap_or_default();
-            super::exit::view(exit, has_visited_connected_room)
+            let knows_trap = knows_all
+                || exit
+                    .trap
+                    .as_ref()
+                    .is_some_and(|trap| known_trap_ids.contains(&trap.id));
+            super::exit::view(exit, has_visited_connected_room, knows_trap)
         })
         .into_iter()
         .collect();
@@ -51,10 +61,21 @@ pub fn view(
         npc_positions,
         flavour: room.flavour,
         exits,
+        depth: room.depth,
+        coordinates: room.coordinates,
+        traps: room
+            .traps
+            .iter()
+            .filter(|trap| trap.armed && (knows_all || known_trap_ids.contains(&trap.id)))
+            .map(super::trap::view)
+            .collect(),
         loose_items: room
             .loose_items
             .iter()
-            .map(|item| super::item::view(item, true, knows_all))
+            .map(|item| {
+                let args = item_args.get(&item.id).cloned().unwrap_or_default();
+                super::item::view(item, &args, true, knows_all)
+            })
             .collect(),
     }
 }
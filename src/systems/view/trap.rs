@@ -0,0 +1,8 @@
+use crate::components::{Trap, TrapView};
+
+pub fn view(trap: &Trap) -> TrapView {
+    TrapView {
+        id: trap.id.to_string(),
+        trap_type: trap.trap_type,
+    }
+}
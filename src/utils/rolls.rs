@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use rand::{prelude::ThreadRng, Rng};
 
 /**
@@ -23,3 +25,13 @@ pub fn roll_d100(rng: &mut ThreadRng, num_rolls: usize, modifier: i32) -> i32 {
 pub fn roll_percent_succeeds(rng: &mut ThreadRng, percent: i32) -> bool {
     roll_d100(rng, 1, 0) <= percent
 }
+
+/**
+ * Rolls anywhere in a range, both ends included. A range that was written
+ * backwards, such as one loaded from a file, rolls between its two ends
+ * instead of panicking.
+ */
+pub fn roll_range(rng: &mut ThreadRng, range: &RangeInclusive<i32>) -> i32 {
+    let (start, end) = (*range.start(), *range.end());
+    rng.gen_range(start.min(end)..=start.max(end))
+}